@@ -19,6 +19,7 @@ contract ShariaCompliance is Ownable {
         string symbol;
         address tokenAddress;   // ← ADD: Token contract address
         bool verified;
+        uint256 verifiedUntil;  // Certification expiry timestamp (0 = no expiry)
         string complianceReason;
         bool exists;
     }
@@ -40,6 +41,21 @@ contract ShariaCompliance is Ownable {
     mapping(address => string) public addressToSymbol;  // Address → Symbol
     mapping(string => address) public symbolToAddress;  // Symbol → Address
 
+    /// @notice Coins with new buys halted (compliance status is unaffected)
+    mapping(string => bool) public coinPaused;
+
+    /// @notice Why new buys of a paused coin are halted (e.g. "Under re-review")
+    mapping(string => string) public coinPauseReason;
+
+    /// @notice Maximum length of a coin pause reason in bytes
+    uint256 public constant MAX_PAUSE_REASON_LENGTH = 256;
+
+    /// @notice Disclaimer to display wherever compliance is asserted
+    string public complianceDisclaimer;
+
+    /// @notice Maximum length of the compliance disclaimer in bytes
+    uint256 public constant MAX_DISCLAIMER_LENGTH = 1024;
+
     // ============================================================================
     // EVENTS
     // ============================================================================
@@ -59,6 +75,12 @@ contract ShariaCompliance is Ownable {
         string complianceReason
     );
 
+    event CoinPauseUpdated(string indexed coinId, bool paused, string reason);
+
+    event VerificationRenewed(string indexed coinId, uint256 verifiedUntil);
+
+    event ComplianceDisclaimerUpdated(string disclaimer);
+
     // ============================================================================
     // ERRORS
     // ============================================================================
@@ -66,6 +88,8 @@ contract ShariaCompliance is Ownable {
     error CoinNotFound(string coinId);
     error CoinAlreadyExists(string coinId);
     error NotShariaCompliant(string coinId);
+    error CoinPaused(string coinId);
+    error TextTooLong();
 
     // ============================================================================
     // CONSTRUCTOR
@@ -86,13 +110,15 @@ contract ShariaCompliance is Ownable {
      * @param name Token name
      * @param symbol Token symbol
      * @param complianceReason Explanation of Sharia compliance
+     * @param verifiedUntil Timestamp at which the certification lapses (0 = no expiry)
      */
     function registerShariaCoin(
         string memory coinId,
         string memory name,
         string memory symbol,
         address tokenAddress,  // ← ADD parameter
-        string memory complianceReason
+        string memory complianceReason,
+        uint256 verifiedUntil
     ) external onlyOwner {
         if (shariaCoins[coinId].exists) {
             revert CoinAlreadyExists(coinId);
@@ -119,6 +145,7 @@ contract ShariaCompliance is Ownable {
             symbol: symbol,
             tokenAddress: tokenAddress,  // ← Store address
             verified: true,
+            verifiedUntil: verifiedUntil,
             complianceReason: complianceReason,
             exists: true
         });
@@ -159,6 +186,8 @@ contract ShariaCompliance is Ownable {
         }
         
         coinIdExists[coinId] = false;
+        delete coinPaused[coinId];
+        delete coinPauseReason[coinId];
 
         emit CoinRemoved(coinId);
     }
@@ -184,6 +213,57 @@ contract ShariaCompliance is Ownable {
         emit CoinUpdated(coinId, verified, complianceReason);
     }
 
+    /**
+     * @notice Extend (or clear) the certification expiry of a coin
+     * @param coinId Coin identifier
+     * @param verifiedUntil New expiry timestamp (0 = no expiry)
+     */
+    function renewVerification(string memory coinId, uint256 verifiedUntil) external onlyOwner {
+        if (!shariaCoins[coinId].exists) {
+            revert CoinNotFound(coinId);
+        }
+
+        shariaCoins[coinId].verifiedUntil = verifiedUntil;
+
+        emit VerificationRenewed(coinId, verifiedUntil);
+    }
+
+    /**
+     * @notice Halt or resume new buys of a coin
+     * @dev A paused coin stays Sharia compliant; only new investment into it is blocked
+     * @param coinId Coin identifier
+     * @param paused New pause status
+     * @param reason Why buys are halted (ignored and cleared when resuming)
+     */
+    function setCoinPaused(string memory coinId, bool paused, string memory reason) external onlyOwner {
+        if (!shariaCoins[coinId].exists) {
+            revert CoinNotFound(coinId);
+        }
+        if (bytes(reason).length > MAX_PAUSE_REASON_LENGTH) revert TextTooLong();
+
+        coinPaused[coinId] = paused;
+        if (paused) {
+            coinPauseReason[coinId] = reason;
+        } else {
+            delete coinPauseReason[coinId];
+            reason = "";
+        }
+
+        emit CoinPauseUpdated(coinId, paused, reason);
+    }
+
+    /**
+     * @notice Set the disclaimer shown alongside compliance claims
+     * @param disclaimer Disclaimer text (empty to clear, at most MAX_DISCLAIMER_LENGTH bytes)
+     */
+    function setComplianceDisclaimer(string memory disclaimer) external onlyOwner {
+        if (bytes(disclaimer).length > MAX_DISCLAIMER_LENGTH) revert TextTooLong();
+
+        complianceDisclaimer = disclaimer;
+
+        emit ComplianceDisclaimerUpdated(disclaimer);
+    }
+
     // ============================================================================
     // VIEW FUNCTIONS
     // ============================================================================
@@ -191,10 +271,25 @@ contract ShariaCompliance is Ownable {
     /**
      * @notice Check if a coin is Sharia compliant
      * @param coinId Coin identifier to check
-     * @return bool True if compliant and verified
+     * @return bool True if compliant, verified and certification has not lapsed
      */
     function isShariaCompliant(string memory coinId) public view returns (bool) {
-        return shariaCoins[coinId].exists && shariaCoins[coinId].verified;
+        ShariaCoin storage coin = shariaCoins[coinId];
+        return coin.exists &&
+            coin.verified &&
+            (coin.verifiedUntil == 0 || block.timestamp < coin.verifiedUntil);
+    }
+
+    /**
+     * @notice Check compliance of several coins in one call
+     * @param ids Coin identifiers to check
+     * @return results Compliance status for each coin, in input order
+     */
+    function checkComplianceBatch(string[] memory ids) external view returns (bool[] memory results) {
+        results = new bool[](ids.length);
+        for (uint256 i = 0; i < ids.length; i++) {
+            results[i] = isShariaCompliant(ids[i]);
+        }
     }
 
     /**
@@ -209,6 +304,53 @@ contract ShariaCompliance is Ownable {
         return shariaCoins[coinId];
     }
 
+    /**
+     * @notice Get details of a Sharia coin together with the compliance disclaimer
+     * @param coinId Coin identifier
+     * @return coin ShariaCoin struct with all details
+     * @return disclaimer Current compliance disclaimer
+     */
+    function getCoinWithDisclaimer(
+        string memory coinId
+    ) external view returns (ShariaCoin memory coin, string memory disclaimer) {
+        if (!shariaCoins[coinId].exists) {
+            revert CoinNotFound(coinId);
+        }
+        return (shariaCoins[coinId], complianceDisclaimer);
+    }
+
+    /**
+     * @notice Get the compliance disclaimer
+     * @return Disclaimer text (empty if not set)
+     */
+    function getComplianceDisclaimer() external view returns (string memory) {
+        return complianceDisclaimer;
+    }
+
+    /**
+     * @notice Get the compliance reason of a Sharia coin
+     * @param coinId Coin identifier
+     * @return Compliance reason string
+     */
+    function getComplianceReason(string memory coinId) external view returns (string memory) {
+        if (!shariaCoins[coinId].exists) {
+            revert CoinNotFound(coinId);
+        }
+        return shariaCoins[coinId].complianceReason;
+    }
+
+    /**
+     * @notice Get why new buys of a coin are paused
+     * @param coinId Coin identifier
+     * @return Pause reason (empty if the coin is not paused)
+     */
+    function getCoinPauseReason(string memory coinId) external view returns (string memory) {
+        if (!shariaCoins[coinId].exists) {
+            revert CoinNotFound(coinId);
+        }
+        return coinPauseReason[coinId];
+    }
+
     /**
      * @notice Get all registered Sharia coins
      * @return Array of all ShariaCoin structs
@@ -223,6 +365,45 @@ contract ShariaCompliance is Ownable {
         return coins;
     }
 
+    /**
+     * @notice Get coins that are currently Sharia compliant
+     * @dev Unlike getAllShariaCoins, skips unverified coins and lapsed certifications
+     * @return Array of compliant ShariaCoin structs
+     */
+    function getVerifiedCoins() external view returns (ShariaCoin[] memory) {
+        uint256 count = 0;
+        for (uint256 i = 0; i < coinIds.length; i++) {
+            if (isShariaCompliant(coinIds[i])) count++;
+        }
+
+        ShariaCoin[] memory coins = new ShariaCoin[](count);
+        uint256 index = 0;
+        for (uint256 i = 0; i < coinIds.length; i++) {
+            if (isShariaCompliant(coinIds[i])) {
+                coins[index] = shariaCoins[coinIds[i]];
+                index++;
+            }
+        }
+
+        return coins;
+    }
+
+    /**
+     * @notice Get id and symbol of every registered coin
+     * @dev Lightweight alternative to getAllShariaCoins for labelling tokens in the UI
+     * @return ids Coin identifiers
+     * @return symbols Coin symbols, index-aligned with ids
+     */
+    function getCoinSymbols() external view returns (string[] memory ids, string[] memory symbols) {
+        ids = new string[](coinIds.length);
+        symbols = new string[](coinIds.length);
+
+        for (uint256 i = 0; i < coinIds.length; i++) {
+            ids[i] = coinIds[i];
+            symbols[i] = shariaCoins[coinIds[i]].symbol;
+        }
+    }
+
     /**
      * @notice Get total number of registered coins
      * @return uint256 Total count
@@ -241,6 +422,65 @@ contract ShariaCompliance is Ownable {
         }
     }
 
+    /**
+     * @notice Check if a coin can be bought (Sharia compliant and not paused)
+     * @param coinId Coin identifier to check
+     * @return bool True if new buys of the coin are allowed
+     */
+    function isInvestable(string memory coinId) public view returns (bool) {
+        return isShariaCompliant(coinId) && !coinPaused[coinId];
+    }
+
+    /**
+     * @notice Require that a coin is Sharia compliant and not paused (reverts if not)
+     * @param coinId Coin identifier to validate
+     */
+    function requireInvestable(string memory coinId) public view {
+        requireShariaCompliant(coinId);
+        if (coinPaused[coinId]) {
+            revert CoinPaused(coinId);
+        }
+    }
+
+    /**
+     * @notice Find symbols shared by more than one registered coin
+     * @dev Case-insensitive, O(n²) over the registry; intended for admin cleanup only
+     * @return duplicates Each duplicated symbol once, as spelled by its first coin
+     */
+    function findDuplicateSymbols() external view onlyOwner returns (string[] memory duplicates) {
+        uint256 total = coinIds.length;
+        bytes32[] memory keys = new bytes32[](total);
+        for (uint256 i = 0; i < total; i++) {
+            keys[i] = keccak256(_toLower(bytes(shariaCoins[coinIds[i]].symbol)));
+        }
+
+        string[] memory found = new string[](total);
+        uint256 count = 0;
+
+        for (uint256 i = 0; i < total; i++) {
+            bool seenBefore = false;
+            bool repeatedLater = false;
+            for (uint256 j = 0; j < total; j++) {
+                if (j == i || keys[j] != keys[i]) continue;
+                if (j < i) {
+                    seenBefore = true;
+                    break;
+                }
+                repeatedLater = true;
+            }
+
+            if (!seenBefore && repeatedLater) {
+                found[count] = shariaCoins[coinIds[i]].symbol;
+                count++;
+            }
+        }
+
+        duplicates = new string[](count);
+        for (uint256 i = 0; i < count; i++) {
+            duplicates[i] = found[i];
+        }
+    }
+
     // Add helper functions
     function getCoinByAddress(address tokenAddress) external view returns (ShariaCoin memory) {
         string memory symbol = addressToSymbol[tokenAddress];
@@ -279,6 +519,23 @@ contract ShariaCompliance is Ownable {
         // See scripts/deploy-core.ts for registration logic
     }
 
+    /**
+     * @notice Lowercase ASCII letters of a string
+     * @param str String bytes
+     * @return Lowercased copy
+     */
+    function _toLower(bytes memory str) private pure returns (bytes memory) {
+        bytes memory lower = new bytes(str.length);
+        for (uint256 i = 0; i < str.length; i++) {
+            bytes1 char = str[i];
+            if (char >= 0x41 && char <= 0x5A) {
+                char = bytes1(uint8(char) + 32);
+            }
+            lower[i] = char;
+        }
+        return lower;
+    }
+
     /**
      * @notice Internal function to register a coin during initialization
      * @dev This function is deprecated - use registerShariaCoin() instead
@@ -310,6 +567,7 @@ contract ShariaCompliance is Ownable {
             symbol: symbol,
             tokenAddress: tokenAddress,
             verified: true,
+            verifiedUntil: 0,
             complianceReason: complianceReason,
             exists: true
         });
@@ -44,6 +44,57 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
     /// @notice User's DCA orders
     mapping(address => uint256[]) public userOrders;
 
+    /// @notice Number of active DCA orders across all users
+    uint256 public activeOrderCount;
+
+    /// @notice Total amount executed through DCA orders per source token (address(0) for DEV)
+    mapping(address => uint256) public totalDCAVolume;
+
+    /// @notice Amount held in escrow for active orders per source token (address(0) for DEV)
+    mapping(address => uint256) public totalEscrowed;
+
+    /// @notice Amount a user has spent buying a token through DCA: user => target => source (address(0) for DEV)
+    mapping(address => mapping(address => mapping(address => uint256))) public costBasis;
+
+    /// @notice (target, source) pairs each user has cost basis in, so it can be migrated
+    mapping(address => TokenPair[]) internal costBasisPairs;
+
+    /// @notice Amount an order has invested so far (intervals may have run at different amounts)
+    mapping(uint256 => uint256) public amountInvested;
+
+    /// @notice Failed upkeep executions in a row before an order is auto-paused (0 = never)
+    uint256 public maxConsecutiveFailures;
+
+    /// @notice Failed upkeep executions in a row per order (reset on success)
+    mapping(uint256 => uint256) public consecutiveFailures;
+
+    /// @notice Start of the due window in which an order last had a failure counted
+    mapping(uint256 => uint256) public lastFailedDueTime;
+
+    /// @notice Orders deactivated after repeated failures (escrow kept until resumed or cancelled)
+    mapping(uint256 => bool) public autoPaused;
+
+    /// @notice User's preferred target token for DCA orders
+    mapping(address => address) public defaultTargetToken;
+
+    /// @notice Address that referred each user (set once; attributes DCA volume only, not ShariaSwap swaps)
+    mapping(address => address) public referrer;
+
+    /// @notice DCA volume executed by referred users, per referrer and source token
+    mapping(address => mapping(address => uint256)) public referredVolume;
+
+    /// @notice Source tokens each referrer has referred volume in, so it can be migrated
+    mapping(address => address[]) internal referredVolumeSources;
+
+    /// @notice Platform-suggested amount per interval for new orders (0 = no suggestion)
+    uint256 public recommendedDCAAmount;
+
+    /// @notice Platform-suggested interval in seconds for new orders (0 = no suggestion)
+    uint256 public recommendedDCAInterval;
+
+    /// @notice Permanently disables new orders and executions once set (cancellations still work)
+    bool public terminated;
+
     // Token addresses are stored in ShariaCompliance contract
 
     /// @notice Minimum interval between executions (1 hour)
@@ -52,12 +103,24 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
     /// @notice Maximum interval between executions (30 days)
     uint256 public constant MAX_INTERVAL = 30 days;
 
+    /// @notice Decimals of the native DEV unit used by DEV-funded orders
+    uint8 public constant NATIVE_DECIMALS = 18;
+
     /// @notice Block time in seconds (configurable via constructor or setBlockTime)
     uint256 public blockTime;
     
     /// @notice Number of blocks before hour to make orders ready (configurable via constructor or setBlocksBeforeHour)
     uint256 public blocksBeforeHour;
     
+    /// @notice Maximum length of a DCA order label in bytes
+    uint256 public constant MAX_LABEL_LENGTH = 32;
+
+    /// @notice Maximum number of orders per executeDCAOrdersBatch call
+    uint256 public constant MAX_BATCH_SIZE = 50;
+
+    /// @notice Maximum number of execution times returned by previewDCASchedule
+    uint256 public constant MAX_PREVIEW_ENTRIES = 52;
+
     /// @notice Hour in seconds (constant)
     uint256 private constant HOUR_IN_SECONDS = 3600;
 
@@ -78,6 +141,29 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
         uint256 startTime;
         bool isActive;
         bool exists;
+        string label;             // Optional user-defined name (e.g. "retirement")
+    }
+
+    struct TokenPair {
+        address targetToken;
+        address sourceToken;      // address(0) for DEV
+    }
+
+    struct DCAExecutionResult {
+        uint256 amountIn;
+        uint256 amountOut;
+        uint256 intervalsCompleted;
+        bool isActive;
+    }
+
+    enum DCAExecutionOutcome {
+        Executed,
+        NotFound,
+        Inactive,
+        NotReady,
+        Terminated,
+        SwapFailed,
+        NotInvestable
     }
 
     // ============================================================================
@@ -113,6 +199,42 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
         uint256 totalIntervals
     );
 
+    event DCAOrderModified(
+        uint256 indexed orderId,
+        uint256 amountPerInterval,
+        uint256 interval,
+        uint256 totalIntervals
+    );
+
+    event DCAOrderAutoPaused(
+        uint256 indexed orderId,
+        address indexed owner,
+        uint256 failures
+    );
+
+    event DCAOrderResumed(uint256 indexed orderId);
+
+    event DCAOrderLabelSet(uint256 indexed orderId, string label);
+
+    event UserOrdersPruned(address indexed user, uint256 pruned);
+
+    event DCATerminated(address indexed by);
+
+    event DCARecommendationsSet(uint256 amountPerInterval, uint256 interval);
+
+    event ReferrerSet(address indexed user, address indexed referrer);
+
+    event DefaultTargetTokenSet(
+        address indexed user,
+        address indexed targetToken
+    );
+
+    event AccountMigrated(
+        address indexed from,
+        address indexed to,
+        uint256 ordersMoved
+    );
+
     // ============================================================================
     // ERRORS
     // ============================================================================
@@ -126,6 +248,23 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
     error OrderNotReady();
     error SwapFailed();
     error TokenNotRegistered();
+    error NoDefaultToken();
+    error InvalidMigration();
+    error ContractTerminated();
+    error LabelTooLong();
+    error Overflow();
+    error BatchTooLarge();
+    error InvalidReferrer();
+    error ReferrerAlreadySet();
+
+    // ============================================================================
+    // MODIFIERS
+    // ============================================================================
+
+    modifier whenNotTerminated() {
+        if (terminated) revert ContractTerminated();
+        _;
+    }
 
     // ============================================================================
     // CONSTRUCTOR
@@ -180,6 +319,95 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
         blocksBeforeHour = _blocks;
     }
 
+    /**
+     * @notice Set how many failed upkeep executions in a row auto-pause an order
+     * @param _maxFailures Failure threshold (0 disables auto-pausing)
+     */
+    function setMaxConsecutiveFailures(uint256 _maxFailures) external onlyOwner {
+        maxConsecutiveFailures = _maxFailures;
+    }
+
+    /**
+     * @notice Publish suggested defaults for the new order form
+     * @dev Pass (0, 0) to withdraw the suggestion
+     * @param amountPerInterval Suggested amount per interval (in wei)
+     * @param intervalSeconds Suggested interval (between MIN_INTERVAL and MAX_INTERVAL)
+     */
+    function setDCARecommendations(uint256 amountPerInterval, uint256 intervalSeconds) external onlyOwner {
+        if (amountPerInterval != 0 || intervalSeconds != 0) {
+            if (amountPerInterval == 0) revert InvalidAmount();
+            if (intervalSeconds < MIN_INTERVAL || intervalSeconds > MAX_INTERVAL) revert InvalidInterval();
+        }
+
+        recommendedDCAAmount = amountPerInterval;
+        recommendedDCAInterval = intervalSeconds;
+
+        emit DCARecommendationsSet(amountPerInterval, intervalSeconds);
+    }
+
+    /**
+     * @notice Permanently stop new DCA orders and executions
+     * @dev Irreversible. Users can still cancel active orders to recover their escrow.
+     */
+    function terminate() external onlyOwner whenNotTerminated {
+        terminated = true;
+        emit DCATerminated(msg.sender);
+    }
+
+    /**
+     * @notice Move all of a user's DCA orders to a new address (e.g. key rotation)
+     * @dev Orders are appended to the destination's list and re-owned, so future
+     *      swap outputs and refunds go to the new address. Cost basis and referred volume
+     *      earned by from move to to; users who named from as their referrer keep pointing at from.
+     * @param from Address being migrated away from
+     * @param to Destination address
+     */
+    function migrateAccount(address from, address to) external onlyOwner {
+        if (from == to || to == address(0)) revert InvalidMigration();
+
+        uint256[] storage fromOrders = userOrders[from];
+        uint256[] storage toOrders = userOrders[to];
+        uint256 count = fromOrders.length;
+
+        for (uint256 i = 0; i < count; i++) {
+            uint256 orderId = fromOrders[i];
+            dcaOrders[orderId].owner = to;
+            toOrders.push(orderId);
+        }
+        delete userOrders[from];
+
+        // Carry over the default target token unless the destination already chose one
+        if (defaultTargetToken[to] == address(0)) {
+            defaultTargetToken[to] = defaultTargetToken[from];
+        }
+        delete defaultTargetToken[from];
+
+        // Same for the referrer, so referred volume keeps being attributed
+        if (referrer[to] == address(0) && referrer[from] != to) {
+            referrer[to] = referrer[from];
+        }
+        delete referrer[from];
+
+        TokenPair[] storage pairs = costBasisPairs[from];
+        for (uint256 i = 0; i < pairs.length; i++) {
+            TokenPair memory pair = pairs[i];
+            uint256 spent = costBasis[from][pair.targetToken][pair.sourceToken];
+            delete costBasis[from][pair.targetToken][pair.sourceToken];
+            _addCostBasis(to, pair.targetToken, pair.sourceToken, spent);
+        }
+        delete costBasisPairs[from];
+
+        address[] storage sources = referredVolumeSources[from];
+        for (uint256 i = 0; i < sources.length; i++) {
+            uint256 volume = referredVolume[from][sources[i]];
+            delete referredVolume[from][sources[i]];
+            _addReferredVolume(to, sources[i], volume);
+        }
+        delete referredVolumeSources[from];
+
+        emit AccountMigrated(from, to, count);
+    }
+
     // ============================================================================
     // INTERNAL HELPER FUNCTIONS
     // ============================================================================
@@ -249,39 +477,83 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
         }
     }
 
-    // ============================================================================
-    // DCA FUNCTIONS
-    // ============================================================================
+    /**
+     * @notice Revert unless token is registered and Sharia compliant
+     * @param token Token address to validate
+     */
+    function _requireShariaToken(address token) internal view {
+        string memory symbol = shariaCompliance.getSymbolByAddress(token);
+        if (bytes(symbol).length == 0) {
+            revert TokenNotRegistered();
+        }
+        if (!shariaCompliance.isShariaCompliant(symbol)) {
+            revert ShariaCompliance.NotShariaCompliant(symbol);
+        }
+    }
 
     /**
-     * @notice Create a new DCA order with native DEV
-     * @param targetToken Target token address
-     * @param amountPerInterval Amount to invest per interval (in wei)
-     * @param intervalSeconds Time between executions (in seconds)
-     * @param totalIntervals Total number of intervals
-     * @return orderId Created order ID
+     * @notice Revert unless token can be bought (registered, compliant and not paused)
+     * @param token Token address to validate
      */
-    function createDCAOrderWithDEV(
+    function _requireInvestableToken(address token) internal view {
+        string memory symbol = shariaCompliance.getSymbolByAddress(token);
+        if (bytes(symbol).length == 0) {
+            revert TokenNotRegistered();
+        }
+        shariaCompliance.requireInvestable(symbol);
+    }
+
+    /**
+     * @notice Add to a user's cost basis, remembering the token pair the first time
+     * @param user User address
+     * @param targetToken Token bought
+     * @param sourceToken Token spent (address(0) for DEV)
+     * @param amount Amount spent, in source token units
+     */
+    function _addCostBasis(address user, address targetToken, address sourceToken, uint256 amount) internal {
+        if (costBasis[user][targetToken][sourceToken] == 0) {
+            costBasisPairs[user].push(TokenPair(targetToken, sourceToken));
+        }
+        costBasis[user][targetToken][sourceToken] += amount;
+    }
+
+    /**
+     * @notice Attribute volume to a referrer, remembering the source token the first time
+     * @param _referrer Referrer address
+     * @param sourceToken Source token address (address(0) for DEV)
+     * @param amount Volume to add
+     */
+    function _addReferredVolume(address _referrer, address sourceToken, uint256 amount) internal {
+        if (referredVolume[_referrer][sourceToken] == 0) {
+            referredVolumeSources[_referrer].push(sourceToken);
+        }
+        referredVolume[_referrer][sourceToken] += amount;
+    }
+
+    /**
+     * @notice Create a DEV-funded DCA order for msg.sender
+     * @dev Shared by createDCAOrderWithDEV and createDCAOrderWithDEVDefault
+     */
+    function _createDCAOrderWithDEV(
         address targetToken,
         uint256 amountPerInterval,
         uint256 intervalSeconds,
-        uint256 totalIntervals
-    ) external payable nonReentrant returns (uint256) {
+        uint256 totalIntervals,
+        string calldata label
+    ) internal returns (uint256) {
         if (amountPerInterval == 0 || totalIntervals == 0) {
             revert InvalidAmount();
         }
-
-        // Validate target token is Sharia compliant
-        string memory targetSymbol = shariaCompliance.getSymbolByAddress(targetToken);
-        if (bytes(targetSymbol).length == 0) {
-            revert TokenNotRegistered();
-        }
-        if (!shariaCompliance.isShariaCompliant(targetSymbol)) {
-            revert ShariaCompliance.NotShariaCompliant(targetSymbol);
+        if (intervalSeconds < MIN_INTERVAL || intervalSeconds > MAX_INTERVAL) {
+            revert InvalidInterval();
         }
+        if (bytes(label).length > MAX_LABEL_LENGTH) revert LabelTooLong();
+
+        // Validate target token is Sharia compliant and not paused
+        _requireInvestableToken(targetToken);
 
         // Check deposit
-        uint256 totalRequired = amountPerInterval * totalIntervals;
+        uint256 totalRequired = _commitment(amountPerInterval, totalIntervals);
         if (msg.value < totalRequired) {
             revert InsufficientDeposit();
         }
@@ -303,8 +575,11 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
         order.startTime = block.timestamp;
         order.isActive = true;
         order.exists = true;
+        order.label = label;
 
         userOrders[msg.sender].push(orderId);
+        activeOrderCount++;
+        totalEscrowed[address(0)] += totalRequired;
 
         // Refund excess DEV
         if (msg.value > totalRequired) {
@@ -326,45 +601,33 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
     }
 
     /**
-     * @notice Create a new DCA order with ERC20 tokens
-     * @param sourceToken Source token address
-     * @param targetToken Target token address
-     * @param amountPerInterval Amount to invest per interval (in wei)
-     * @param intervalSeconds Time between executions (in seconds)
-     * @param totalIntervals Total number of intervals
-     * @return orderId Created order ID
+     * @notice Create a token-funded DCA order for msg.sender
+     * @dev Shared by createDCAOrderWithToken and createDCAOrderWithTokenDefault
      */
-    function createDCAOrderWithToken(
+    function _createDCAOrderWithToken(
         address sourceToken,
         address targetToken,
         uint256 amountPerInterval,
         uint256 intervalSeconds,
-        uint256 totalIntervals
-    ) external nonReentrant returns (uint256) {
+        uint256 totalIntervals,
+        string calldata label
+    ) internal returns (uint256) {
         if (amountPerInterval == 0 || totalIntervals == 0) {
             revert InvalidAmount();
         }
+        if (intervalSeconds < MIN_INTERVAL || intervalSeconds > MAX_INTERVAL) {
+            revert InvalidInterval();
+        }
+        if (bytes(label).length > MAX_LABEL_LENGTH) revert LabelTooLong();
 
         // Validate source token is Sharia compliant
-        string memory sourceSymbol = shariaCompliance.getSymbolByAddress(sourceToken);
-        if (bytes(sourceSymbol).length == 0) {
-            revert TokenNotRegistered();
-        }
-        if (!shariaCompliance.isShariaCompliant(sourceSymbol)) {
-            revert ShariaCompliance.NotShariaCompliant(sourceSymbol);
-        }
+        _requireShariaToken(sourceToken);
 
-        // Validate target token is Sharia compliant
-        string memory targetSymbol = shariaCompliance.getSymbolByAddress(targetToken);
-        if (bytes(targetSymbol).length == 0) {
-            revert TokenNotRegistered();
-        }
-        if (!shariaCompliance.isShariaCompliant(targetSymbol)) {
-            revert ShariaCompliance.NotShariaCompliant(targetSymbol);
-        }
+        // Validate target token is Sharia compliant and not paused
+        _requireInvestableToken(targetToken);
 
         // Transfer tokens from user upfront
-        uint256 totalRequired = amountPerInterval * totalIntervals;
+        uint256 totalRequired = _commitment(amountPerInterval, totalIntervals);
         IERC20(sourceToken).safeTransferFrom(msg.sender, address(this), totalRequired);
 
         // Create order
@@ -384,8 +647,11 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
         order.startTime = block.timestamp;
         order.isActive = true;
         order.exists = true;
+        order.label = label;
 
         userOrders[msg.sender].push(orderId);
+        activeOrderCount++;
+        totalEscrowed[sourceToken] += totalRequired;
 
         emit DCAOrderCreated(
             orderId,
@@ -401,136 +667,558 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
     }
 
     /**
-     * @notice Execute a DCA order (called by automation script or manually)
-     * @param orderId Order ID to execute
+     * @notice Total amount committed by an order schedule
+     * @dev Reverts with Overflow instead of a bare arithmetic panic so clients get a clear error
+     * @param amountPerInterval Amount invested per interval
+     * @param intervals Number of intervals
+     * @return Product of amountPerInterval and intervals
      */
-    function executeDCAOrder(uint256 orderId) public nonReentrant {
-        DCAOrder storage order = dcaOrders[orderId];
-        
-        if (!order.exists) revert OrderNotFound();
-        if (!order.isActive) revert OrderInactive();
-        if (block.timestamp < order.nextExecutionTime) revert OrderNotReady();
-
-        // Prepare swap
-        address tokenIn;
-        uint256 amountIn = order.amountPerInterval;
-        
-        // Handle source token (DEV or ERC20)
-        if (order.sourceToken == address(0)) {
-            // Wrap DEV to WETH
-            IWETH(WETH).deposit{value: amountIn}();
-            tokenIn = WETH;
-        } else {
-            // Use ERC20 token directly
-            tokenIn = order.sourceToken;
+    function _commitment(uint256 amountPerInterval, uint256 intervals) internal pure returns (uint256) {
+        if (intervals != 0 && amountPerInterval > type(uint256).max / intervals) {
+            revert Overflow();
         }
+        return amountPerInterval * intervals;
+    }
 
-        // Approve router
-        IERC20(tokenIn).forceApprove(address(dexRouter), amountIn);
+    /**
+     * @notice Settle the difference between an order's current and required escrow
+     * @dev Pulls the shortfall from msg.sender (msg.value for DEV orders) or refunds the surplus.
+     *      Any DEV sent beyond the shortfall is refunded.
+     * @param order Order being modified
+     * @param oldEscrow Amount currently held for the order's remaining intervals
+     * @param newEscrow Amount required for the order's remaining intervals after modification
+     */
+    function _settleEscrow(DCAOrder storage order, uint256 oldEscrow, uint256 newEscrow) internal {
+        totalEscrowed[order.sourceToken] = totalEscrowed[order.sourceToken] - oldEscrow + newEscrow;
 
-        // Get USDC address for routing
-        address usdc = shariaCompliance.getTokenAddress("USDC");
-        
-        // Build optimal swap path using library
-        address[] memory path = SwapPathBuilder.buildSwapPath(
-            address(factory),
-            tokenIn,
-            order.targetToken,
-            usdc
-        );
+        uint256 topUp = newEscrow > oldEscrow ? newEscrow - oldEscrow : 0;
+        uint256 refund = oldEscrow > newEscrow ? oldEscrow - newEscrow : 0;
 
-        // Execute swap
-        uint256[] memory amounts;
-        try dexRouter.swapExactTokensForTokens(
-            amountIn,
-            0, // No slippage protection for DCA
-            path,
-            order.owner,
-            block.timestamp + 15 minutes
-        ) returns (uint256[] memory _amounts) {
-            amounts = _amounts;
-        } catch {
-            revert SwapFailed();
-        }
+        if (order.sourceToken == address(0)) {
+            if (msg.value < topUp) revert InsufficientDeposit();
+            refund += msg.value - topUp;
 
-        uint256 amountOut = amounts[amounts.length - 1];
+            if (refund > 0) {
+                (bool success, ) = msg.sender.call{value: refund}("");
+                require(success, "Refund failed");
+            }
+        } else {
+            if (msg.value > 0) revert InvalidAmount();
 
-        // Update order
-        order.intervalsCompleted++;
-        // Round to next hour boundary minus blocks (configurable) for subsequent executions
-        order.nextExecutionTime = _calculateNextExecutionTime(block.timestamp, order.interval);
+            if (topUp > 0) {
+                IERC20(order.sourceToken).safeTransferFrom(msg.sender, address(this), topUp);
+            }
+            if (refund > 0) {
+                IERC20(order.sourceToken).safeTransfer(msg.sender, refund);
+            }
+        }
+    }
 
-        emit DCAOrderExecuted(
-            orderId,
-            order.intervalsCompleted,
-            order.amountPerInterval,
-            amountOut,
-            block.timestamp
-        );
+    /**
+     * @notice Count a failed upkeep execution and auto-pause the order past the threshold
+     * @dev Only orders that should have executed count, so calls for orders that aren't ready
+     *      or whose target is paused or non-compliant are ignored
+     * @param orderId Order ID whose execution failed
+     */
+    function _recordFailure(uint256 orderId) internal {
+        if (_executionStatus(orderId) != DCAExecutionOutcome.Executed) return;
+        DCAOrder storage order = dcaOrders[orderId];
 
-        // Check if completed
-        if (order.intervalsCompleted >= order.totalIntervals) {
+        // Count one failure per interval-long window since the order became due, so retries
+        // (duplicate ids, several upkeeps in one block) don't pause it early
+        uint256 dueTime = order.nextExecutionTime +
+            ((block.timestamp - order.nextExecutionTime) / order.interval) * order.interval;
+        if (lastFailedDueTime[orderId] == dueTime) return;
+        lastFailedDueTime[orderId] = dueTime;
+
+        uint256 failures = ++consecutiveFailures[orderId];
+        if (maxConsecutiveFailures != 0 && failures >= maxConsecutiveFailures) {
             order.isActive = false;
-            emit DCAOrderCompleted(orderId, order.owner, order.totalIntervals);
+            autoPaused[orderId] = true;
+            activeOrderCount--;
+            emit DCAOrderAutoPaused(orderId, order.owner, failures);
         }
     }
 
     /**
-     * @notice Cancel a DCA order and refund remaining balance
-     * @param orderId Order ID to cancel
+     * @notice Check whether an order can be executed right now
+     * @param orderId Order ID
+     * @return Executed if the order is ready, otherwise the reason it is not
      */
-    function cancelDCAOrder(uint256 orderId) external nonReentrant {
+    function _executionStatus(uint256 orderId) internal view returns (DCAExecutionOutcome) {
         DCAOrder storage order = dcaOrders[orderId];
-        
-        if (!order.exists) revert OrderNotFound();
-        if (order.owner != msg.sender) revert Unauthorized();
-        if (!order.isActive) revert OrderInactive();
 
-        // Calculate refund
-        uint256 remaining = order.totalIntervals - order.intervalsCompleted;
-        uint256 refundAmount = remaining * order.amountPerInterval;
+        if (terminated) return DCAExecutionOutcome.Terminated;
+        if (!order.exists) return DCAExecutionOutcome.NotFound;
+        if (!order.isActive) return DCAExecutionOutcome.Inactive;
+        if (block.timestamp < order.nextExecutionTime) return DCAExecutionOutcome.NotReady;
+        // Paused or no longer compliant (e.g. certification lapsed) targets are skipped, not bought
+        string memory symbol = shariaCompliance.getSymbolByAddress(order.targetToken);
+        if (!shariaCompliance.isInvestable(symbol)) return DCAExecutionOutcome.NotInvestable;
+        return DCAExecutionOutcome.Executed;
+    }
 
-        // Deactivate order
-        order.isActive = false;
+    /**
+     * @notice Resolve a bounded range of order IDs for views that scan orders
+     * @param offset Number of orders to skip, starting from order ID 1
+     * @param maxScan Maximum number of order IDs to examine
+     * @return first First order ID to examine
+     * @return end Order ID to stop before (equal to first when the range is empty)
+     */
+    function _scanWindow(uint256 offset, uint256 maxScan) internal view returns (uint256 first, uint256 end) {
+        uint256 total = nextOrderId - 1;
+        if (offset >= total) {
+            return (nextOrderId, nextOrderId);
+        }
 
-        // Refund
-        if (refundAmount > 0) {
-            if (order.sourceToken == address(0)) {
-                // Refund DEV
-                (bool success, ) = msg.sender.call{value: refundAmount}("");
-                require(success, "Refund failed");
-            } else {
-                // Refund ERC20
-                IERC20(order.sourceToken).safeTransfer(msg.sender, refundAmount);
+        uint256 count = total - offset;
+        if (count > maxScan) count = maxScan;
+
+        first = offset + 1;
+        end = first + count;
+    }
+
+    /**
+     * @notice Execute each order, recording failures instead of reverting
+     * @param orderIds Order IDs to execute
+     * @return succeeded Whether each order executed
+     */
+    function _executeBatch(uint256[] memory orderIds) internal returns (bool[] memory succeeded) {
+        succeeded = new bool[](orderIds.length);
+
+        for (uint256 i = 0; i < orderIds.length; i++) {
+            // Use external call (this.) to enable try-catch error handling
+            // This allows one failed order to not block execution of other orders
+            try this.executeDCAOrder(orderIds[i]) {
+                succeeded[i] = true;
+            } catch {
+                // Continue - failed order will be retried in next upkeep cycle
+                _recordFailure(orderIds[i]);
             }
         }
-
-        emit DCAOrderCancelled(orderId, msg.sender);
     }
 
     // ============================================================================
-    // AUTOMATION FUNCTIONS
+    // DCA FUNCTIONS
     // ============================================================================
 
     /**
-     * @notice Check if upkeep is needed (for automation script)
-     * @dev Checks all active orders to see if any need execution
-     * @return upkeepNeeded Whether upkeep is needed
-     * @return performData Encoded order IDs to execute
-     */
-    function checkUpkeep(
+     * @notice Create a new DCA order with native DEV
+     * @param targetToken Target token address
+     * @param amountPerInterval Amount to invest per interval (in wei)
+     * @param intervalSeconds Time between executions (in seconds, between MIN_INTERVAL and MAX_INTERVAL)
+     * @param totalIntervals Total number of intervals
+     * @param label Optional order name (empty for none, at most MAX_LABEL_LENGTH bytes)
+     * @return orderId Created order ID
+     */
+    function createDCAOrderWithDEV(
+        address targetToken,
+        uint256 amountPerInterval,
+        uint256 intervalSeconds,
+        uint256 totalIntervals,
+        string calldata label
+    ) external payable nonReentrant whenNotTerminated returns (uint256) {
+        return _createDCAOrderWithDEV(targetToken, amountPerInterval, intervalSeconds, totalIntervals, label);
+    }
+
+    /**
+     * @notice Create a new DCA order with native DEV into the caller's default target token
+     * @param amountPerInterval Amount to invest per interval (in wei)
+     * @param intervalSeconds Time between executions (in seconds, between MIN_INTERVAL and MAX_INTERVAL)
+     * @param totalIntervals Total number of intervals
+     * @param label Optional order name (empty for none, at most MAX_LABEL_LENGTH bytes)
+     * @return orderId Created order ID
+     */
+    function createDCAOrderWithDEVDefault(
+        uint256 amountPerInterval,
+        uint256 intervalSeconds,
+        uint256 totalIntervals,
+        string calldata label
+    ) external payable nonReentrant whenNotTerminated returns (uint256) {
+        address targetToken = defaultTargetToken[msg.sender];
+        if (targetToken == address(0)) revert NoDefaultToken();
+
+        return _createDCAOrderWithDEV(targetToken, amountPerInterval, intervalSeconds, totalIntervals, label);
+    }
+
+    /**
+     * @notice Create a new DCA order with ERC20 tokens into the caller's default target token
+     * @param sourceToken Source token address
+     * @param amountPerInterval Amount to invest per interval (in wei)
+     * @param intervalSeconds Time between executions (in seconds, between MIN_INTERVAL and MAX_INTERVAL)
+     * @param totalIntervals Total number of intervals
+     * @param label Optional order name (empty for none, at most MAX_LABEL_LENGTH bytes)
+     * @return orderId Created order ID
+     */
+    function createDCAOrderWithTokenDefault(
+        address sourceToken,
+        uint256 amountPerInterval,
+        uint256 intervalSeconds,
+        uint256 totalIntervals,
+        string calldata label
+    ) external nonReentrant whenNotTerminated returns (uint256) {
+        address targetToken = defaultTargetToken[msg.sender];
+        if (targetToken == address(0)) revert NoDefaultToken();
+
+        return _createDCAOrderWithToken(
+            sourceToken,
+            targetToken,
+            amountPerInterval,
+            intervalSeconds,
+            totalIntervals,
+            label
+        );
+    }
+
+    /**
+     * @notice Set or clear the caller's default target token for DCA orders
+     * @param targetToken Target token address (must be Sharia compliant, address(0) to clear)
+     */
+    function setDefaultTargetToken(address targetToken) external {
+        if (targetToken != address(0)) {
+            _requireShariaToken(targetToken);
+        }
+
+        defaultTargetToken[msg.sender] = targetToken;
+
+        emit DefaultTargetTokenSet(msg.sender, targetToken);
+    }
+
+    /**
+     * @notice Record who referred the caller
+     * @dev Can only be set once and not to the caller itself. Only the caller's DCA executions
+     *      are attributed to the referrer; ShariaSwap swaps are not tracked.
+     * @param _referrer Referrer address
+     */
+    function setReferrer(address _referrer) external {
+        if (_referrer == address(0) || _referrer == msg.sender) revert InvalidReferrer();
+        if (referrer[msg.sender] != address(0)) revert ReferrerAlreadySet();
+
+        referrer[msg.sender] = _referrer;
+
+        emit ReferrerSet(msg.sender, _referrer);
+    }
+
+    /**
+     * @notice Create a new DCA order with ERC20 tokens
+     * @param sourceToken Source token address
+     * @param targetToken Target token address
+     * @param amountPerInterval Amount to invest per interval (in wei)
+     * @param intervalSeconds Time between executions (in seconds, between MIN_INTERVAL and MAX_INTERVAL)
+     * @param totalIntervals Total number of intervals
+     * @param label Optional order name (empty for none, at most MAX_LABEL_LENGTH bytes)
+     * @return orderId Created order ID
+     */
+    function createDCAOrderWithToken(
+        address sourceToken,
+        address targetToken,
+        uint256 amountPerInterval,
+        uint256 intervalSeconds,
+        uint256 totalIntervals,
+        string calldata label
+    ) external nonReentrant whenNotTerminated returns (uint256) {
+        return _createDCAOrderWithToken(
+            sourceToken,
+            targetToken,
+            amountPerInterval,
+            intervalSeconds,
+            totalIntervals,
+            label
+        );
+    }
+
+    /**
+     * @notice Execute a DCA order (called by automation script or manually)
+     * @param orderId Order ID to execute
+     * @return result Amounts swapped and the order's progress after this execution
+     */
+    function executeDCAOrder(
+        uint256 orderId
+    ) public nonReentrant whenNotTerminated returns (DCAExecutionResult memory result) {
+        DCAExecutionOutcome status = _executionStatus(orderId);
+        if (status == DCAExecutionOutcome.NotFound) revert OrderNotFound();
+        if (status == DCAExecutionOutcome.Inactive) revert OrderInactive();
+        if (status == DCAExecutionOutcome.NotReady) revert OrderNotReady();
+        if (status == DCAExecutionOutcome.NotInvestable) {
+            // Reverts with the specific reason (CoinPaused, NotShariaCompliant, ...)
+            _requireInvestableToken(dcaOrders[orderId].targetToken);
+        }
+
+        DCAOrder storage order = dcaOrders[orderId];
+
+        // Prepare swap
+        address tokenIn;
+        uint256 amountIn = order.amountPerInterval;
+        
+        // Handle source token (DEV or ERC20)
+        if (order.sourceToken == address(0)) {
+            // Wrap DEV to WETH
+            IWETH(WETH).deposit{value: amountIn}();
+            tokenIn = WETH;
+        } else {
+            // Use ERC20 token directly
+            tokenIn = order.sourceToken;
+        }
+
+        // Approve router
+        IERC20(tokenIn).forceApprove(address(dexRouter), amountIn);
+
+        // Get USDC address for routing
+        address usdc = shariaCompliance.getTokenAddress("USDC");
+        
+        // Build optimal swap path using library
+        address[] memory path = SwapPathBuilder.buildSwapPath(
+            address(factory),
+            tokenIn,
+            order.targetToken,
+            usdc
+        );
+
+        // Execute swap
+        uint256[] memory amounts;
+        try dexRouter.swapExactTokensForTokens(
+            amountIn,
+            0, // No slippage protection for DCA
+            path,
+            order.owner,
+            block.timestamp + 15 minutes
+        ) returns (uint256[] memory _amounts) {
+            amounts = _amounts;
+        } catch {
+            revert SwapFailed();
+        }
+
+        uint256 amountOut = amounts[amounts.length - 1];
+
+        // Update order
+        order.intervalsCompleted++;
+        consecutiveFailures[orderId] = 0;
+        amountInvested[orderId] += amountIn;
+        totalDCAVolume[order.sourceToken] += amountIn;
+        _addCostBasis(order.owner, order.targetToken, order.sourceToken, amountIn);
+        address orderReferrer = referrer[order.owner];
+        if (orderReferrer != address(0)) {
+            _addReferredVolume(orderReferrer, order.sourceToken, amountIn);
+        }
+        totalEscrowed[order.sourceToken] -= amountIn;
+        // Round to next hour boundary minus blocks (configurable) for subsequent executions
+        order.nextExecutionTime = _calculateNextExecutionTime(block.timestamp, order.interval);
+
+        emit DCAOrderExecuted(
+            orderId,
+            order.intervalsCompleted,
+            order.amountPerInterval,
+            amountOut,
+            block.timestamp
+        );
+
+        // Check if completed
+        if (order.intervalsCompleted >= order.totalIntervals) {
+            order.isActive = false;
+            activeOrderCount--;
+            emit DCAOrderCompleted(orderId, order.owner, order.totalIntervals);
+        }
+
+        return DCAExecutionResult({
+            amountIn: amountIn,
+            amountOut: amountOut,
+            intervalsCompleted: order.intervalsCompleted,
+            isActive: order.isActive
+        });
+    }
+
+    /**
+     * @notice Cancel a DCA order and refund remaining balance
+     * @param orderId Order ID to cancel
+     */
+    function cancelDCAOrder(uint256 orderId) external nonReentrant {
+        DCAOrder storage order = dcaOrders[orderId];
+        
+        if (!order.exists) revert OrderNotFound();
+        if (order.owner != msg.sender) revert Unauthorized();
+        if (!order.isActive && !autoPaused[orderId]) revert OrderInactive();
+
+        // Calculate refund
+        uint256 remaining = order.totalIntervals - order.intervalsCompleted;
+        uint256 refundAmount = remaining * order.amountPerInterval;
+
+        // Deactivate order (auto-paused orders were already removed from the active count)
+        if (order.isActive) activeOrderCount--;
+        order.isActive = false;
+        autoPaused[orderId] = false;
+        totalEscrowed[order.sourceToken] -= refundAmount;
+
+        // Refund
+        if (refundAmount > 0) {
+            if (order.sourceToken == address(0)) {
+                // Refund DEV
+                (bool success, ) = msg.sender.call{value: refundAmount}("");
+                require(success, "Refund failed");
+            } else {
+                // Refund ERC20
+                IERC20(order.sourceToken).safeTransfer(msg.sender, refundAmount);
+            }
+        }
+
+        emit DCAOrderCancelled(orderId, msg.sender);
+    }
+
+    /**
+     * @notice Reactivate an order that was auto-paused after repeated failures
+     * @param orderId Order ID to resume
+     */
+    function resumeDCAOrder(uint256 orderId) external whenNotTerminated {
+        DCAOrder storage order = dcaOrders[orderId];
+
+        if (!order.exists) revert OrderNotFound();
+        if (order.owner != msg.sender) revert Unauthorized();
+        if (!autoPaused[orderId]) revert OrderInactive();
+
+        order.isActive = true;
+        autoPaused[orderId] = false;
+        consecutiveFailures[orderId] = 0;
+        delete lastFailedDueTime[orderId];
+        activeOrderCount++;
+
+        emit DCAOrderResumed(orderId);
+    }
+
+    /**
+     * @notice Change the amount and interval of an active DCA order
+     * @dev Keeps intervalsCompleted and totalIntervals. Escrow for the remaining intervals is
+     *      topped up (send DEV or approve tokens) or refunded to match the new amount.
+     * @param orderId Order ID to modify
+     * @param amountPerInterval New amount to invest per interval
+     * @param intervalSeconds New time between executions (in seconds)
+     */
+    function modifyDCAOrder(
+        uint256 orderId,
+        uint256 amountPerInterval,
+        uint256 intervalSeconds
+    ) external payable nonReentrant whenNotTerminated {
+        DCAOrder storage order = dcaOrders[orderId];
+
+        if (!order.exists) revert OrderNotFound();
+        if (order.owner != msg.sender) revert Unauthorized();
+        if (!order.isActive) revert OrderInactive();
+        if (amountPerInterval == 0) revert InvalidAmount();
+        if (intervalSeconds < MIN_INTERVAL || intervalSeconds > MAX_INTERVAL) {
+            revert InvalidInterval();
+        }
+
+        uint256 remaining = order.totalIntervals - order.intervalsCompleted;
+        uint256 oldEscrow = remaining * order.amountPerInterval;
+        uint256 newEscrow = _commitment(amountPerInterval, remaining);
+
+        order.amountPerInterval = amountPerInterval;
+        order.interval = intervalSeconds;
+        order.nextExecutionTime = _calculateNextExecutionTime(block.timestamp, intervalSeconds);
+
+        _settleEscrow(order, oldEscrow, newEscrow);
+
+        emit DCAOrderModified(orderId, amountPerInterval, intervalSeconds, order.totalIntervals);
+    }
+
+    /**
+     * @notice Change the total number of intervals of an active DCA order
+     * @dev Lowering the cap refunds the surplus escrow; raising it requires a top-up (send DEV
+     *      or approve tokens). A cap at or below intervalsCompleted completes the order now.
+     * @param orderId Order ID to modify
+     * @param totalIntervals New total number of intervals
+     */
+    function setDCATotalIntervals(uint256 orderId, uint256 totalIntervals) external payable nonReentrant {
+        DCAOrder storage order = dcaOrders[orderId];
+
+        if (!order.exists) revert OrderNotFound();
+        if (order.owner != msg.sender) revert Unauthorized();
+        if (!order.isActive) revert OrderInactive();
+        if (totalIntervals > order.totalIntervals && terminated) revert ContractTerminated();
+
+        uint256 oldEscrow = (order.totalIntervals - order.intervalsCompleted) * order.amountPerInterval;
+
+        if (totalIntervals <= order.intervalsCompleted) {
+            order.totalIntervals = order.intervalsCompleted;
+            order.isActive = false;
+            activeOrderCount--;
+            emit DCAOrderCompleted(orderId, order.owner, order.totalIntervals);
+        } else {
+            order.totalIntervals = totalIntervals;
+        }
+
+        uint256 newEscrow = _commitment(order.amountPerInterval, order.totalIntervals - order.intervalsCompleted);
+        _settleEscrow(order, oldEscrow, newEscrow);
+
+        emit DCAOrderModified(orderId, order.amountPerInterval, order.interval, order.totalIntervals);
+    }
+
+    /**
+     * @notice Set or clear the label of a DCA order
+     * @param orderId Order ID
+     * @param label New label (empty to clear, at most MAX_LABEL_LENGTH bytes)
+     */
+    function setDCALabel(uint256 orderId, string calldata label) external {
+        DCAOrder storage order = dcaOrders[orderId];
+
+        if (!order.exists) revert OrderNotFound();
+        if (order.owner != msg.sender) revert Unauthorized();
+        if (bytes(label).length > MAX_LABEL_LENGTH) revert LabelTooLong();
+
+        order.label = label;
+
+        emit DCAOrderLabelSet(orderId, label);
+    }
+
+    /**
+     * @notice Remove the caller's completed and cancelled orders from their order list
+     * @dev Order records stay in dcaOrders for history; auto-paused orders are kept.
+     *      Remaining orders keep their creation order.
+     * @return pruned Number of order IDs removed
+     */
+    function pruneUserOrders() external returns (uint256 pruned) {
+        uint256[] storage orders = userOrders[msg.sender];
+        uint256 kept = 0;
+
+        for (uint256 i = 0; i < orders.length; i++) {
+            uint256 orderId = orders[i];
+            if (dcaOrders[orderId].isActive || autoPaused[orderId]) {
+                orders[kept] = orderId;
+                kept++;
+            }
+        }
+
+        pruned = orders.length - kept;
+        for (uint256 i = 0; i < pruned; i++) {
+            orders.pop();
+        }
+
+        emit UserOrdersPruned(msg.sender, pruned);
+    }
+
+    // ============================================================================
+    // AUTOMATION FUNCTIONS
+    // ============================================================================
+
+    /**
+     * @notice Check if upkeep is needed (for automation script)
+     * @dev Checks all active orders to see if any need execution, skipping paused or non-compliant targets
+     * @return upkeepNeeded Whether upkeep is needed
+     * @return performData Encoded order IDs to execute
+     */
+    function checkUpkeep(
         bytes calldata /* checkData */
     ) external view returns (bool upkeepNeeded, bytes memory performData) {
+        if (terminated) {
+            return (false, performData);
+        }
+
         uint256[] memory ordersToExecute = new uint256[](nextOrderId);
         uint256 count = 0;
 
         for (uint256 i = 1; i < nextOrderId; i++) {
-            DCAOrder storage order = dcaOrders[i];
+            // Skips orders whose target is paused or no longer compliant, which would only revert
             if (
-                order.exists &&
-                order.isActive &&
-                block.timestamp >= order.nextExecutionTime &&
-                order.intervalsCompleted < order.totalIntervals
+                _executionStatus(i) == DCAExecutionOutcome.Executed &&
+                dcaOrders[i].intervalsCompleted < dcaOrders[i].totalIntervals
             ) {
                 ordersToExecute[count] = i;
                 count++;
@@ -551,6 +1239,62 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
         return (upkeepNeeded, performData);
     }
 
+    /**
+     * @notice Get due orders in a range of order IDs, most overdue first
+     * @dev Lets a keeper that can only afford part of the backlog prioritize the most-behind orders.
+     *      Keeps only the best `limit` candidates while scanning, so cost grows with maxScan * limit.
+     * @param offset Number of orders to skip, starting from order ID 1
+     * @param maxScan Maximum number of order IDs to examine
+     * @param limit Maximum number of order IDs to return (at most MAX_BATCH_SIZE)
+     * @return Order IDs sorted by nextExecutionTime ascending (ties by order ID)
+     */
+    function getDueOrdersSorted(
+        uint256 offset,
+        uint256 maxScan,
+        uint256 limit
+    ) external view returns (uint256[] memory) {
+        if (limit > MAX_BATCH_SIZE) limit = MAX_BATCH_SIZE;
+        if (terminated || limit == 0) {
+            return new uint256[](0);
+        }
+
+        (uint256 first, uint256 end) = _scanWindow(offset, maxScan);
+
+        uint256[] memory due = new uint256[](limit);
+        uint256[] memory dueTimes = new uint256[](limit);
+        uint256 count = 0;
+
+        for (uint256 i = first; i < end; i++) {
+            DCAOrder storage order = dcaOrders[i];
+            uint256 dueTime = order.nextExecutionTime;
+            if (!order.isActive || block.timestamp < dueTime) continue;
+
+            // Buffer full and this order is no more overdue than the last kept one
+            if (count == limit && dueTimes[count - 1] <= dueTime) continue;
+            // Due but not buyable (target paused or no longer compliant)
+            if (_executionStatus(i) != DCAExecutionOutcome.Executed) continue;
+
+            // Insertion into the bounded buffer, dropping the last entry when full;
+            // strict comparison keeps earlier IDs first on ties
+            uint256 j = count < limit ? count++ : limit - 1;
+            while (j > 0 && dueTimes[j - 1] > dueTime) {
+                due[j] = due[j - 1];
+                dueTimes[j] = dueTimes[j - 1];
+                j--;
+            }
+            due[j] = i;
+            dueTimes[j] = dueTime;
+        }
+
+        // Resize array
+        uint256[] memory result = new uint256[](count);
+        for (uint256 i = 0; i < count; i++) {
+            result[i] = due[i];
+        }
+
+        return result;
+    }
+
     /**
      * @notice Perform upkeep (called by automation script)
      * @param performData Encoded order IDs to execute
@@ -558,16 +1302,39 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
      */
     function performUpkeep(bytes calldata performData) external {
         uint256[] memory orderIds = abi.decode(performData, (uint256[]));
-        
-        for (uint256 i = 0; i < orderIds.length; i++) {
-            // Use external call (this.) to enable try-catch error handling
-            // This allows one failed order to not block execution of other orders
-            try this.executeDCAOrder(orderIds[i]) {
-                // Order executed successfully - event emitted in executeDCAOrder
-            } catch {
-                // Silently continue - failed order will be retried in next upkeep cycle
-                // This prevents one failed order from blocking batch execution
-            }
+        _executeBatch(orderIds);
+    }
+
+    /**
+     * @notice Execute several DCA orders in one transaction
+     * @dev Failed orders are skipped (and count towards auto-pause) instead of reverting the batch
+     * @param orderIds Order IDs to execute (at most MAX_BATCH_SIZE)
+     * @return succeeded Whether each order executed, in the same order as orderIds
+     */
+    function executeDCAOrdersBatch(uint256[] calldata orderIds) external returns (bool[] memory succeeded) {
+        if (orderIds.length > MAX_BATCH_SIZE) revert BatchTooLarge();
+        return _executeBatch(orderIds);
+    }
+
+    /**
+     * @notice Execute a DCA order if it is ready, returning why not instead of reverting
+     * @param orderId Order ID to execute
+     * @return outcome Executed, or the reason the order was skipped
+     * @return amountOut Target tokens received (0 unless executed)
+     */
+    function tryExecuteDCAOrder(
+        uint256 orderId
+    ) external returns (DCAExecutionOutcome outcome, uint256 amountOut) {
+        outcome = _executionStatus(orderId);
+        if (outcome != DCAExecutionOutcome.Executed) {
+            return (outcome, 0);
+        }
+
+        try this.executeDCAOrder(orderId) returns (DCAExecutionResult memory result) {
+            amountOut = result.amountOut;
+        } catch {
+            _recordFailure(orderId);
+            outcome = DCAExecutionOutcome.SwapFailed;
         }
     }
 
@@ -584,6 +1351,123 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
         return dcaOrders[orderId];
     }
 
+    /**
+     * @notice Get amount still to be invested by an order if it runs to completion
+     * @param orderId Order ID
+     * @return Remaining commitment in source token units
+     */
+    function getRemainingCommitment(uint256 orderId) external view returns (uint256) {
+        DCAOrder storage order = dcaOrders[orderId];
+        if (!order.exists) revert OrderNotFound();
+        if (!order.isActive && !autoPaused[orderId]) return 0;
+        return (order.totalIntervals - order.intervalsCompleted) * order.amountPerInterval;
+    }
+
+    /**
+     * @notice Get total amount an order commits over its full lifetime
+     * @dev Amount invested so far plus the remaining intervals at the current amount, so
+     *      intervals run before a modifyDCAOrder are counted at the amount they actually used.
+     *      Cancelled orders were refunded, so only what they invested counts.
+     * @param orderId Order ID
+     * @return Lifetime commitment in source token units
+     */
+    function getTotalCommitment(uint256 orderId) external view returns (uint256) {
+        DCAOrder storage order = dcaOrders[orderId];
+        if (!order.exists) revert OrderNotFound();
+        if (!order.isActive && !autoPaused[orderId]) return amountInvested[orderId];
+        return amountInvested[orderId] +
+            (order.totalIntervals - order.intervalsCompleted) * order.amountPerInterval;
+    }
+
+    /**
+     * @notice Page through every DCA order (admin oversight)
+     * @param offset Number of orders to skip, starting from order ID 1
+     * @param limit Maximum number of orders to return
+     * @return Array of orders, active and inactive, in ID order
+     */
+    function getAllDCAOrders(
+        uint256 offset,
+        uint256 limit
+    ) external view onlyOwner returns (DCAOrder[] memory) {
+        uint256 total = nextOrderId - 1;
+        if (offset >= total) {
+            return new DCAOrder[](0);
+        }
+
+        uint256 count = total - offset;
+        if (count > limit) count = limit;
+
+        DCAOrder[] memory result = new DCAOrder[](count);
+        for (uint256 i = 0; i < count; i++) {
+            result[i] = dcaOrders[offset + i + 1];
+        }
+
+        return result;
+    }
+
+    /**
+     * @notice Get active DCA orders buying a given token within a range of order IDs
+     * @dev Page through all orders by advancing offset by maxScan until offset reaches nextOrderId - 1
+     * @param targetToken Target token address
+     * @param offset Number of orders to skip, starting from order ID 1
+     * @param maxScan Maximum number of order IDs to examine
+     * @return Array of matching active orders, in ID order
+     */
+    function getDCAOrdersByToken(
+        address targetToken,
+        uint256 offset,
+        uint256 maxScan
+    ) external view returns (DCAOrder[] memory) {
+        (uint256 first, uint256 end) = _scanWindow(offset, maxScan);
+
+        DCAOrder[] memory matches = new DCAOrder[](end - first);
+        uint256 count = 0;
+
+        for (uint256 i = first; i < end; i++) {
+            DCAOrder storage order = dcaOrders[i];
+            if (order.isActive && order.targetToken == targetToken) {
+                matches[count] = order;
+                count++;
+            }
+        }
+
+        // Resize array
+        DCAOrder[] memory result = new DCAOrder[](count);
+        for (uint256 i = 0; i < count; i++) {
+            result[i] = matches[i];
+        }
+
+        return result;
+    }
+
+    /**
+     * @notice Get scheduled DCA demand for a token funded from a given source token
+     * @dev ETF demand is not tracked on-chain, so only active DCA orders are counted. Totals cover
+     *      one range of order IDs; sum the results of consecutive ranges for the full picture.
+     * @param targetToken Token being bought
+     * @param sourceToken Token being spent (address(0) for DEV)
+     * @param offset Number of orders to skip, starting from order ID 1
+     * @param maxScan Maximum number of order IDs to examine
+     * @return activeOrders Number of active orders in range buying targetToken with sourceToken
+     * @return totalPerInterval Sum of amountPerInterval across those orders, in source token units
+     */
+    function getTokenDCADemand(
+        address targetToken,
+        address sourceToken,
+        uint256 offset,
+        uint256 maxScan
+    ) external view returns (uint256 activeOrders, uint256 totalPerInterval) {
+        (uint256 first, uint256 end) = _scanWindow(offset, maxScan);
+
+        for (uint256 i = first; i < end; i++) {
+            DCAOrder storage order = dcaOrders[i];
+            if (order.isActive && order.targetToken == targetToken && order.sourceToken == sourceToken) {
+                activeOrders++;
+                totalPerInterval += order.amountPerInterval;
+            }
+        }
+    }
+
     /**
      * @notice Get user's DCA orders
      * @param user User address
@@ -592,6 +1476,139 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
         return userOrders[user];
     }
 
+    /**
+     * @notice Get number of active DCA orders across all users
+     */
+    function getActiveOrderCount() external view returns (uint256) {
+        return activeOrderCount;
+    }
+
+    /**
+     * @notice Get total amount executed through DCA orders for a source token
+     * @param sourceToken Source token address (address(0) for DEV)
+     */
+    function getTotalDCAVolume(address sourceToken) external view returns (uint256) {
+        return totalDCAVolume[sourceToken];
+    }
+
+    /**
+     * @notice Get amount held in escrow for active orders of a source token
+     * @dev For reconciliation: the contract's balance of the token should never be lower than this
+     * @param sourceToken Source token address (address(0) for DEV)
+     */
+    function getTotalEscrowed(address sourceToken) external view returns (uint256) {
+        return totalEscrowed[sourceToken];
+    }
+
+    /**
+     * @notice Get the contract's native DEV balance
+     * @dev Compare with getTotalEscrowed(address(0)); any surplus is unaccounted DEV
+     */
+    function getContractNativeBalance() external view returns (uint256) {
+        return address(this).balance;
+    }
+
+    /**
+     * @notice Get how much a user has spent buying a token through DCA
+     * @param user User address
+     * @param targetToken Token bought
+     * @param sourceToken Token spent (address(0) for DEV)
+     * @return Cumulative amount spent, in source token units
+     */
+    function getUserCostBasis(
+        address user,
+        address targetToken,
+        address sourceToken
+    ) external view returns (uint256) {
+        return costBasis[user][targetToken][sourceToken];
+    }
+
+    /**
+     * @notice Get DCA volume executed by users a referrer brought in
+     * @param _referrer Referrer address
+     * @param sourceToken Source token address (address(0) for DEV)
+     */
+    function getReferredVolume(address _referrer, address sourceToken) external view returns (uint256) {
+        return referredVolume[_referrer][sourceToken];
+    }
+
+    /**
+     * @notice Preview when an order created now would execute
+     * @dev Assumes each execution happens at its scheduled time
+     * @param intervalSeconds Interval between executions
+     * @param totalIntervals Number of executions (only the first MAX_PREVIEW_ENTRIES are returned)
+     * @return schedule Projected execution timestamps
+     */
+    function previewDCASchedule(
+        uint256 intervalSeconds,
+        uint256 totalIntervals
+    ) external view returns (uint256[] memory schedule) {
+        if (intervalSeconds < MIN_INTERVAL || intervalSeconds > MAX_INTERVAL) revert InvalidInterval();
+
+        uint256 count = totalIntervals > MAX_PREVIEW_ENTRIES ? MAX_PREVIEW_ENTRIES : totalIntervals;
+        schedule = new uint256[](count);
+        if (count == 0) return schedule;
+
+        schedule[0] = _calculateInitialExecutionTime(block.timestamp);
+        for (uint256 i = 1; i < count; i++) {
+            schedule[i] = _calculateNextExecutionTime(schedule[i - 1], intervalSeconds);
+        }
+    }
+
+    /**
+     * @notice Get platform-suggested defaults for new DCA orders
+     * @return amountPerInterval Suggested amount per interval (0 if not set)
+     * @return interval Suggested interval in seconds (0 if not set)
+     */
+    function getDCARecommendations() external view returns (uint256 amountPerInterval, uint256 interval) {
+        return (recommendedDCAAmount, recommendedDCAInterval);
+    }
+
+    /**
+     * @notice Get user's default DCA target token
+     * @param user User address
+     * @return Target token address (address(0) if not set)
+     */
+    function getDefaultTargetToken(address user) external view returns (address) {
+        return defaultTargetToken[user];
+    }
+
+    /**
+     * @notice Get full details of a user's DCA orders
+     * @param user User address
+     * @param offset Index into the user's order list to start from
+     * @param limit Maximum number of orders to return
+     * @return Array of the user's orders, in creation order
+     */
+    function getUserOrderDetails(
+        address user,
+        uint256 offset,
+        uint256 limit
+    ) external view returns (DCAOrder[] memory) {
+        uint256[] storage orders = userOrders[user];
+        if (offset >= orders.length) {
+            return new DCAOrder[](0);
+        }
+
+        uint256 count = orders.length - offset;
+        if (count > limit) count = limit;
+
+        DCAOrder[] memory result = new DCAOrder[](count);
+        for (uint256 i = 0; i < count; i++) {
+            result[i] = dcaOrders[orders[offset + i]];
+        }
+
+        return result;
+    }
+
+    /**
+     * @notice Get total order count for user (active and inactive)
+     * @param user User address
+     */
+    function getUserOrderCount(address user) external view returns (uint256) {
+        return userOrders[user].length;
+    }
+
     /**
      * @notice Get active order count for user
      * @param user User address
@@ -609,6 +1626,64 @@ contract ShariaDCA is Ownable, ReentrancyGuard {
         return count;
     }
 
+    /**
+     * @notice Get the user's soonest-due active order
+     * @param user User address
+     * @return orderId Order ID (0 if the user has no active orders)
+     * @return nextExecutionTime When that order is next due (0 if none)
+     */
+    function getNextUserExecution(
+        address user
+    ) external view returns (uint256 orderId, uint256 nextExecutionTime) {
+        uint256[] storage orders = userOrders[user];
+
+        for (uint256 i = 0; i < orders.length; i++) {
+            DCAOrder storage order = dcaOrders[orders[i]];
+            if (!order.isActive) continue;
+
+            if (orderId == 0 || order.nextExecutionTime < nextExecutionTime) {
+                orderId = orders[i];
+                nextExecutionTime = order.nextExecutionTime;
+            }
+        }
+    }
+
+    /**
+     * @notice Get user's orders that can be executed right now
+     * @dev Funds are escrowed at creation, so a due active order is always funded. Orders whose
+     *      target is paused or no longer compliant are left out, as executeDCAOrder would revert.
+     * @param user User address
+     * @return Array of order IDs ready for executeDCAOrder
+     */
+    function getUserExecutableOrders(address user) external view returns (uint256[] memory) {
+        if (terminated) {
+            return new uint256[](0);
+        }
+
+        uint256[] storage orders = userOrders[user];
+        uint256[] memory ready = new uint256[](orders.length);
+        uint256 count = 0;
+
+        for (uint256 i = 0; i < orders.length; i++) {
+            DCAOrder storage order = dcaOrders[orders[i]];
+            if (
+                _executionStatus(orders[i]) == DCAExecutionOutcome.Executed &&
+                order.intervalsCompleted < order.totalIntervals
+            ) {
+                ready[count] = orders[i];
+                count++;
+            }
+        }
+
+        // Resize array
+        uint256[] memory result = new uint256[](count);
+        for (uint256 i = 0; i < count; i++) {
+            result[i] = ready[i];
+        }
+
+        return result;
+    }
+
 
     // ============================================================================
     // EMERGENCY FUNCTIONS
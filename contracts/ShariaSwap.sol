@@ -37,6 +37,9 @@ contract ShariaSwap is Ownable, ReentrancyGuard {
     /// @notice Swap history per user
     mapping(address => SwapRecord[]) public userSwapHistory;
 
+    /// @notice Amount a user has spent buying a token: user => tokenOut => tokenIn (address(0) for DEV)
+    mapping(address => mapping(address => mapping(address => uint256))) public costBasis;
+
     // ============================================================================
     // STRUCTS
     // ============================================================================
@@ -149,7 +152,7 @@ contract ShariaSwap is Ownable, ReentrancyGuard {
         string memory tokenOutSymbol = shariaCompliance.getSymbolByAddress(tokenOut);
         if (bytes(tokenOutSymbol).length == 0) revert AssetNotRegistered();
         
-        shariaCompliance.requireShariaCompliant(tokenOutSymbol);
+        shariaCompliance.requireInvestable(tokenOutSymbol);
 
         // Transfer tokens from user
         IERC20(tokenIn).safeTransferFrom(msg.sender, address(this), amountIn);
@@ -187,6 +190,7 @@ contract ShariaSwap is Ownable, ReentrancyGuard {
         }
 
         // Record swap
+        costBasis[msg.sender][tokenOut][tokenIn] += amountIn;
         _recordSwap(
             msg.sender,
             tokenIn,
@@ -227,7 +231,7 @@ contract ShariaSwap is Ownable, ReentrancyGuard {
         string memory tokenOutSymbol = shariaCompliance.getSymbolByAddress(tokenOut);
         if (bytes(tokenOutSymbol).length == 0) revert AssetNotRegistered();
         
-        shariaCompliance.requireShariaCompliant(tokenOutSymbol);
+        shariaCompliance.requireInvestable(tokenOutSymbol);
 
         // Build swap path (auto-routes through USDC if no direct pair)
         // Path must start with WETH for native token swaps
@@ -255,7 +259,8 @@ contract ShariaSwap is Ownable, ReentrancyGuard {
 
         amountOut = amounts[amounts.length - 1];
 
-        // Record swap
+        // Record swap (DEV cost basis is keyed under address(0), like ShariaDCA)
+        costBasis[msg.sender][tokenOut][address(0)] += msg.value;
         _recordSwap(
             msg.sender,
             WETH,
@@ -308,6 +313,21 @@ contract ShariaSwap is Ownable, ReentrancyGuard {
         return userSwapHistory[user];
     }
 
+    /**
+     * @notice Get how much a user has spent buying a token through swaps
+     * @param user User address
+     * @param tokenOut Token bought
+     * @param tokenIn Token spent (address(0) for DEV, matching ShariaDCA)
+     * @return Cumulative amount spent, in tokenIn units
+     */
+    function getUserCostBasis(
+        address user,
+        address tokenOut,
+        address tokenIn
+    ) external view returns (uint256) {
+        return costBasis[user][tokenOut][tokenIn];
+    }
+
     /**
      * @notice Get user's swap count
      * @param user User address
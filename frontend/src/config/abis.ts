@@ -124,6 +124,7 @@ export const ShariaComplianceABI = [
 					{ name: "symbol", type: "string" },
 					{ name: "tokenAddress", type: "address" },
 					{ name: "verified", type: "bool" },
+					{ name: "verifiedUntil", type: "uint256" },
 					{ name: "complianceReason", type: "string" },
 					{ name: "exists", type: "bool" },
 				],
@@ -145,6 +146,7 @@ export const ShariaComplianceABI = [
 					{ name: "symbol", type: "string" },
 					{ name: "tokenAddress", type: "address" },
 					{ name: "verified", type: "bool" },
+					{ name: "verifiedUntil", type: "uint256" },
 					{ name: "complianceReason", type: "string" },
 					{ name: "exists", type: "bool" },
 				],
@@ -187,6 +189,7 @@ export const ShariaComplianceABI = [
 					{ name: "symbol", type: "string" },
 					{ name: "tokenAddress", type: "address" },
 					{ name: "verified", type: "bool" },
+					{ name: "verifiedUntil", type: "uint256" },
 					{ name: "complianceReason", type: "string" },
 					{ name: "exists", type: "bool" },
 				],
@@ -277,6 +280,7 @@ export const ShariaDCAABI = [
 			{ name: "amountPerInterval", type: "uint256" },
 			{ name: "intervalSeconds", type: "uint256" },
 			{ name: "totalIntervals", type: "uint256" },
+			{ name: "label", type: "string" },
 		],
 		outputs: [{ name: "orderId", type: "uint256" }],
 		stateMutability: "payable",
@@ -290,6 +294,7 @@ export const ShariaDCAABI = [
 			{ name: "amountPerInterval", type: "uint256" },
 			{ name: "intervalSeconds", type: "uint256" },
 			{ name: "totalIntervals", type: "uint256" },
+			{ name: "label", type: "string" },
 		],
 		outputs: [{ name: "orderId", type: "uint256" }],
 		stateMutability: "nonpayable",
@@ -298,7 +303,18 @@ export const ShariaDCAABI = [
 		type: "function",
 		name: "executeDCAOrder",
 		inputs: [{ name: "orderId", type: "uint256" }],
-		outputs: [],
+		outputs: [
+			{
+				name: "result",
+				type: "tuple",
+				components: [
+					{ name: "amountIn", type: "uint256" },
+					{ name: "amountOut", type: "uint256" },
+					{ name: "intervalsCompleted", type: "uint256" },
+					{ name: "isActive", type: "bool" },
+				],
+			},
+		],
 		stateMutability: "nonpayable",
 	},
 	{
@@ -329,6 +345,7 @@ export const ShariaDCAABI = [
 					{ name: "startTime", type: "uint256" },
 					{ name: "isActive", type: "bool" },
 					{ name: "exists", type: "bool" },
+					{ name: "label", type: "string" },
 				],
 			},
 		],
@@ -17,6 +17,7 @@ export interface ShariaCoin {
 	symbol: string;
 	tokenAddress: Address;
 	verified: boolean;
+	verifiedUntil: bigint;
 	complianceReason: string;
 	exists: boolean;
 }
@@ -37,10 +38,14 @@ export function useShariaCompliance() {
 		functionName: "getAllShariaCoins",
 	});
 
-	// Type-safe coins data
+	// Type-safe coins data; coins whose certification has lapsed are reported as unverified
 	const coins = useMemo(() => {
 		if (!coinsRaw) return [];
-		return coinsRaw as ShariaCoin[];
+		const now = BigInt(Math.floor(Date.now() / 1000));
+		return (coinsRaw as ShariaCoin[]).map((coin) => ({
+			...coin,
+			verified: coin.verified && (coin.verifiedUntil === 0n || coin.verifiedUntil > now),
+		}));
 	}, [coinsRaw]);
 
 	// Get total number of coins
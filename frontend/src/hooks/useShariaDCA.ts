@@ -34,6 +34,7 @@ export interface DCAOrder {
 	startTime: bigint;
 	isActive: boolean;
 	exists: boolean;
+	label: string;
 }
 
 /**
@@ -121,7 +122,8 @@ export function useShariaDCA() {
 		amountPerInterval: bigint,
 		intervalSeconds: bigint,
 		totalIntervals: bigint,
-		totalValue: bigint
+		totalValue: bigint,
+		label = ""
 	) => {
 		if (!userAddress) throw new Error("Wallet not connected");
 		await validateNetwork(); // Check network before transaction (will prompt to switch if needed)
@@ -130,7 +132,7 @@ export function useShariaDCA() {
 			address: SHARIA_DCA_ADDRESS,
 			abi: ShariaDCAABI,
 			functionName: "createDCAOrderWithDEV",
-			args: [targetToken, amountPerInterval, intervalSeconds, totalIntervals],
+			args: [targetToken, amountPerInterval, intervalSeconds, totalIntervals, label],
 			value: totalValue, // Total amount to be locked
 		});
 	};
@@ -141,7 +143,8 @@ export function useShariaDCA() {
 		targetToken: Address,
 		amountPerInterval: bigint,
 		intervalSeconds: bigint,
-		totalIntervals: bigint
+		totalIntervals: bigint,
+		label = ""
 	) => {
 		if (!userAddress) throw new Error("Wallet not connected");
 		await validateNetwork(); // Check network before transaction (will prompt to switch if needed)
@@ -156,6 +159,7 @@ export function useShariaDCA() {
 				amountPerInterval,
 				intervalSeconds,
 				totalIntervals,
+				label,
 			],
 		});
 	};
@@ -90,6 +90,14 @@ export function getFriendlyErrorMessage(error: unknown): string {
 				return "Price moved too much. Try increasing your slippage tolerance or try again.";
 			}
 
+			// Coin temporarily paused for new buys
+			if (
+				fullError.includes("coinpaused") ||
+				fullError.includes("0xd2dcffcf")
+			) {
+				return "New purchases of this token are temporarily paused. Please try again later.";
+			}
+
 			// Insufficient liquidity
 			if (
 				fullError.includes("liquidity") ||
@@ -47,6 +47,11 @@ async function main() {
       // Import and run sync script logic
       const config = tayebCoinsConfig as TayebCoinsConfig;
       const contractCoins = await shariaCompliance.getAllShariaCoins();
+
+      // Verified coins stop being permissible once their certification lapses
+      const now = BigInt((await ethers.provider.getBlock("latest"))!.timestamp);
+      const isPermissible = (coin: any) =>
+        coin.verified && (coin.verifiedUntil === 0n || coin.verifiedUntil > now);
       
       const contractCoinsMap = new Map<string, any>();
       for (const coin of contractCoins) {
@@ -69,7 +74,7 @@ async function main() {
           contractSymbols.add(jsonCoin.symbol);
           updatedCoins.push({
             ...jsonCoin,
-            permissible: contractCoin.verified,
+            permissible: isPermissible(contractCoin),
             complianceReason: contractCoin.complianceReason,
           });
         } else {
@@ -89,7 +94,7 @@ async function main() {
             decimals: 18,
             complianceReason: contractCoin.complianceReason,
             description: `Auto-synced from contract`,
-            permissible: contractCoin.verified,
+            permissible: isPermissible(contractCoin),
             addresses: {
               moonbase: null,
             },
@@ -14,7 +14,7 @@ import { TayebCoinsConfig, DeployedContracts, TayebCoin } from "../../config/typ
  * 3. Updates JSON to match contract state:
  *    - Adds new coins from contract
  *    - Sets permissible: false for removed coins (keeps them in JSON)
- *    - Updates permissible flag based on contract's verified field
+ *    - Updates permissible flag based on contract's verified field and certification expiry
  *    - Updates complianceReason from contract
  *    - Preserves addresses and other metadata
  * 
@@ -46,6 +46,11 @@ async function main() {
   console.log(`📊 Found ${contractCoins.length} coins in contract`);
   console.log();
 
+  // Verified coins stop being permissible once their certification lapses
+  const now = BigInt((await ethers.provider.getBlock("latest"))!.timestamp);
+  const isPermissible = (coin: any) =>
+    coin.verified && (coin.verifiedUntil === 0n || coin.verifiedUntil > now);
+
   // Create map of contract coins by symbol
   const contractCoinsMap = new Map<string, any>();
   for (const coin of contractCoins) {
@@ -72,11 +77,11 @@ async function main() {
       contractSymbols.add(jsonCoin.symbol);
       updatedCoins.push({
         ...jsonCoin,
-        permissible: contractCoin.verified,
+        permissible: isPermissible(contractCoin),
         complianceReason: contractCoin.complianceReason,
         // Preserve addresses and other metadata
       });
-      console.log(`✅ Updated ${jsonCoin.symbol} - permissible: ${contractCoin.verified}`);
+      console.log(`✅ Updated ${jsonCoin.symbol} - permissible: ${isPermissible(contractCoin)}`);
     } else {
       // Coin not in contract - mark as not permissible but keep in JSON
       updatedCoins.push({
@@ -102,7 +107,7 @@ async function main() {
         decimals: 18, // Default, will need manual update
         complianceReason: contractCoin.complianceReason,
         description: `Auto-synced from contract`,
-        permissible: contractCoin.verified,
+        permissible: isPermissible(contractCoin),
         addresses: {
           moonbase: null,
         },
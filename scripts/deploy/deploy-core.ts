@@ -186,7 +186,8 @@ async function main() {
             coin.name,
             coin.symbol,
             tokenAddress,
-            coin.complianceReason
+            coin.complianceReason,
+            0 // No certification expiry
         );
         await tx.wait();
         console.log(`✅ Registered ${coin.symbol} (${coin.name}) in ShariaCompliance`);
@@ -1,5 +1,6 @@
 import { expect } from "chai";
 import { ethers } from "hardhat";
+import { time } from "@nomicfoundation/hardhat-network-helpers";
 import { ShariaCompliance } from "../typechain-types";
 import { SignerWithAddress } from "@nomicfoundation/hardhat-ethers/signers";
 
@@ -43,7 +44,9 @@ describe("ShariaCompliance", function () {
           "ADA",
           "Cardano",
           "ADA",
-          "Proof-of-stake blockchain"
+          ethers.ZeroAddress,
+          "Proof-of-stake blockchain",
+          0
         )
       )
         .to.emit(shariaCompliance, "CoinRegistered")
@@ -56,22 +59,22 @@ describe("ShariaCompliance", function () {
       await expect(
         shariaCompliance
           .connect(user)
-          .registerShariaCoin("ADA", "Cardano", "ADA", "Test")
+          .registerShariaCoin("ADA", "Cardano", "ADA", ethers.ZeroAddress, "Test", 0)
       ).to.be.revertedWithCustomError(shariaCompliance, "OwnableUnauthorizedAccount");
     });
 
     it("Should not allow duplicate coin registration", async function () {
-      await shariaCompliance.registerShariaCoin("ADA", "Cardano", "ADA", "Test");
+      await shariaCompliance.registerShariaCoin("ADA", "Cardano", "ADA", ethers.ZeroAddress, "Test", 0);
       
       await expect(
-        shariaCompliance.registerShariaCoin("ADA", "Cardano2", "ADA", "Test")
+        shariaCompliance.registerShariaCoin("ADA", "Cardano2", "ADA", ethers.ZeroAddress, "Test", 0)
       ).to.be.revertedWithCustomError(shariaCompliance, "CoinAlreadyExists");
     });
   });
 
   describe("Remove Coin", function () {
     it("Should allow owner to remove coin", async function () {
-      await shariaCompliance.registerShariaCoin("ADA", "Cardano", "ADA", "Test");
+      await shariaCompliance.registerShariaCoin("ADA", "Cardano", "ADA", ethers.ZeroAddress, "Test", 0);
       
       await expect(shariaCompliance.removeShariaCoin("ADA"))
         .to.emit(shariaCompliance, "CoinRemoved")
@@ -89,7 +92,7 @@ describe("ShariaCompliance", function () {
 
   describe("Update Compliance Status", function () {
     it("Should allow owner to update compliance status", async function () {
-      await shariaCompliance.registerShariaCoin("ADA", "Cardano", "ADA", "Test");
+      await shariaCompliance.registerShariaCoin("ADA", "Cardano", "ADA", ethers.ZeroAddress, "Test", 0);
       
       await expect(
         shariaCompliance.updateComplianceStatus("ADA", false, "Under review")
@@ -101,6 +104,38 @@ describe("ShariaCompliance", function () {
     });
   });
 
+  describe("Verification Expiry", function () {
+    it("Should lapse compliance at verifiedUntil and restore it on renewal", async function () {
+      const verifiedUntil = (await time.latest()) + 3600;
+      await shariaCompliance.registerShariaCoin(
+        "ADA",
+        "Cardano",
+        "ADA",
+        ethers.ZeroAddress,
+        "Test",
+        verifiedUntil
+      );
+      expect(await shariaCompliance.isShariaCompliant("ADA")).to.be.true;
+
+      await time.increaseTo(verifiedUntil);
+      expect(await shariaCompliance.isShariaCompliant("ADA")).to.be.false;
+      expect((await shariaCompliance.getShariaCoin("ADA")).verified).to.be.true;
+
+      await expect(shariaCompliance.renewVerification("ADA", 0))
+        .to.emit(shariaCompliance, "VerificationRenewed")
+        .withArgs("ADA", 0);
+      expect(await shariaCompliance.isShariaCompliant("ADA")).to.be.true;
+    });
+
+    it("Should not allow non-owner to renew verification", async function () {
+      await shariaCompliance.registerShariaCoin("ADA", "Cardano", "ADA", ethers.ZeroAddress, "Test", 0);
+
+      await expect(
+        shariaCompliance.connect(user).renewVerification("ADA", 0)
+      ).to.be.revertedWithCustomError(shariaCompliance, "OwnableUnauthorizedAccount");
+    });
+  });
+
   describe("Get Coin Details", function () {
     it("Should return correct coin details", async function () {
       // Register BTC first
@@ -108,7 +143,9 @@ describe("ShariaCompliance", function () {
         "BTC",
         "Bitcoin",
         "BTC",
-        "Decentralized cryptocurrency"
+        ethers.ZeroAddress,
+        "Decentralized cryptocurrency",
+        0
       );
       
       const coin = await shariaCompliance.getShariaCoin("BTC");
@@ -127,13 +164,36 @@ describe("ShariaCompliance", function () {
     });
   });
 
+  describe("Get Compliance Reason", function () {
+    it("Should return the compliance reason of a coin", async function () {
+      await shariaCompliance.registerShariaCoin(
+        "BTC",
+        "Bitcoin",
+        "BTC",
+        ethers.ZeroAddress,
+        "Decentralized cryptocurrency",
+        0
+      );
+
+      expect(await shariaCompliance.getComplianceReason("BTC")).to.equal(
+        "Decentralized cryptocurrency"
+      );
+    });
+
+    it("Should revert for non-existent coin", async function () {
+      await expect(
+        shariaCompliance.getComplianceReason("NONEXISTENT")
+      ).to.be.revertedWithCustomError(shariaCompliance, "CoinNotFound");
+    });
+  });
+
   describe("Get All Coins", function () {
     it("Should return all registered coins", async function () {
       // Register coins first
-      await shariaCompliance.registerShariaCoin("BTC", "Bitcoin", "BTC", "Test");
-      await shariaCompliance.registerShariaCoin("ETH", "Ethereum", "ETH", "Test");
-      await shariaCompliance.registerShariaCoin("USDT", "Tether", "USDT", "Test");
-      await shariaCompliance.registerShariaCoin("USDC", "USD Coin", "USDC", "Test");
+      await shariaCompliance.registerShariaCoin("BTC", "Bitcoin", "BTC", ethers.ZeroAddress, "Test", 0);
+      await shariaCompliance.registerShariaCoin("ETH", "Ethereum", "ETH", ethers.ZeroAddress, "Test", 0);
+      await shariaCompliance.registerShariaCoin("USDT", "Tether", "USDT", ethers.ZeroAddress, "Test", 0);
+      await shariaCompliance.registerShariaCoin("USDC", "USD Coin", "USDC", ethers.ZeroAddress, "Test", 0);
       
       const coins = await shariaCompliance.getAllShariaCoins();
       
@@ -145,10 +205,133 @@ describe("ShariaCompliance", function () {
     });
   });
 
+  describe("Get Verified Coins", function () {
+    it("Should skip unverified and lapsed coins", async function () {
+      const expiry = (await time.latest()) + 3600;
+      await shariaCompliance.registerShariaCoin("BTC", "Bitcoin", "BTC", ethers.ZeroAddress, "Test", 0);
+      await shariaCompliance.registerShariaCoin("ETH", "Ethereum", "ETH", ethers.ZeroAddress, "Test", expiry);
+      await shariaCompliance.registerShariaCoin("USDT", "Tether", "USDT", ethers.ZeroAddress, "Test", 0);
+      await shariaCompliance.updateComplianceStatus("USDT", false, "Under review");
+
+      expect((await shariaCompliance.getVerifiedCoins()).map((coin) => coin.id)).to.deep.equal(["BTC", "ETH"]);
+
+      await time.increaseTo(expiry);
+
+      expect((await shariaCompliance.getVerifiedCoins()).map((coin) => coin.id)).to.deep.equal(["BTC"]);
+    });
+  });
+
+  describe("Get Coin Symbols", function () {
+    it("Should return index-aligned ids and symbols", async function () {
+      await shariaCompliance.registerShariaCoin("BTC", "Bitcoin", "BTC", ethers.ZeroAddress, "Test", 0);
+      await shariaCompliance.registerShariaCoin("ETH", "Ethereum", "ETH", ethers.ZeroAddress, "Test", 0);
+
+      const [ids, symbols] = await shariaCompliance.getCoinSymbols();
+
+      expect(ids).to.deep.equal(["BTC", "ETH"]);
+      expect(symbols).to.deep.equal(["BTC", "ETH"]);
+    });
+  });
+
+  describe("Check Compliance Batch", function () {
+    it("Should report compliance of each coin in input order", async function () {
+      await shariaCompliance.registerShariaCoin("BTC", "Bitcoin", "BTC", ethers.ZeroAddress, "Test", 0);
+      await shariaCompliance.registerShariaCoin("ETH", "Ethereum", "ETH", ethers.ZeroAddress, "Test", 0);
+      await shariaCompliance.updateComplianceStatus("ETH", false, "Under review");
+
+      const results = await shariaCompliance.checkComplianceBatch(["BTC", "ETH", "NONEXISTENT"]);
+
+      expect(results).to.deep.equal([true, false, false]);
+    });
+  });
+
+  describe("Coin Pause", function () {
+    beforeEach(async function () {
+      await shariaCompliance.registerShariaCoin("BTC", "Bitcoin", "BTC", ethers.ZeroAddress, "Test", 0);
+    });
+
+    it("Should block investment in a paused coin while keeping it compliant", async function () {
+      await expect(shariaCompliance.setCoinPaused("BTC", true, "Under re-review"))
+        .to.emit(shariaCompliance, "CoinPauseUpdated")
+        .withArgs("BTC", true, "Under re-review");
+
+      expect(await shariaCompliance.isShariaCompliant("BTC")).to.be.true;
+      await expect(
+        shariaCompliance.requireInvestable("BTC")
+      ).to.be.revertedWithCustomError(shariaCompliance, "CoinPaused");
+
+      await shariaCompliance.setCoinPaused("BTC", false, "");
+      await expect(shariaCompliance.requireInvestable("BTC")).to.not.be.reverted;
+    });
+
+    it("Should expose the pause reason until the coin is resumed", async function () {
+      await shariaCompliance.setCoinPaused("BTC", true, "Under re-review");
+      expect(await shariaCompliance.getCoinPauseReason("BTC")).to.equal("Under re-review");
+
+      await shariaCompliance.setCoinPaused("BTC", false, "ignored");
+      expect(await shariaCompliance.getCoinPauseReason("BTC")).to.equal("");
+
+      await expect(
+        shariaCompliance.setCoinPaused("BTC", true, "x".repeat(257))
+      ).to.be.revertedWithCustomError(shariaCompliance, "TextTooLong");
+    });
+
+    it("Should not allow non-owner to pause a coin", async function () {
+      await expect(
+        shariaCompliance.connect(user).setCoinPaused("BTC", true, "")
+      ).to.be.revertedWithCustomError(shariaCompliance, "OwnableUnauthorizedAccount");
+    });
+  });
+
+  describe("Compliance Disclaimer", function () {
+    const DISCLAIMER = "Screening is informational and not a fatwa.";
+
+    it("Should return the disclaimer with coin details", async function () {
+      await shariaCompliance.registerShariaCoin("BTC", "Bitcoin", "BTC", ethers.ZeroAddress, "Test", 0);
+      await expect(shariaCompliance.setComplianceDisclaimer(DISCLAIMER))
+        .to.emit(shariaCompliance, "ComplianceDisclaimerUpdated")
+        .withArgs(DISCLAIMER);
+
+      const [coin, disclaimer] = await shariaCompliance.getCoinWithDisclaimer("BTC");
+
+      expect(coin.symbol).to.equal("BTC");
+      expect(disclaimer).to.equal(DISCLAIMER);
+      expect(await shariaCompliance.getComplianceDisclaimer()).to.equal(DISCLAIMER);
+    });
+
+    it("Should reject disclaimers that are too long", async function () {
+      await expect(
+        shariaCompliance.setComplianceDisclaimer("x".repeat(1025))
+      ).to.be.revertedWithCustomError(shariaCompliance, "TextTooLong");
+    });
+
+    it("Should not allow non-owner to set the disclaimer", async function () {
+      await expect(
+        shariaCompliance.connect(user).setComplianceDisclaimer(DISCLAIMER)
+      ).to.be.revertedWithCustomError(shariaCompliance, "OwnableUnauthorizedAccount");
+    });
+  });
+
+  describe("Find Duplicate Symbols", function () {
+    it("Should report symbols shared case-insensitively", async function () {
+      await shariaCompliance.registerShariaCoin("BTC", "Bitcoin", "BTC", ethers.ZeroAddress, "Test", 0);
+      await shariaCompliance.registerShariaCoin("WBTC", "Wrapped Bitcoin", "btc", ethers.ZeroAddress, "Test", 0);
+      await shariaCompliance.registerShariaCoin("ETH", "Ethereum", "ETH", ethers.ZeroAddress, "Test", 0);
+
+      expect(await shariaCompliance.findDuplicateSymbols()).to.deep.equal(["BTC"]);
+    });
+
+    it("Should not allow non-owner to scan for duplicates", async function () {
+      await expect(
+        shariaCompliance.connect(user).findDuplicateSymbols()
+      ).to.be.revertedWithCustomError(shariaCompliance, "OwnableUnauthorizedAccount");
+    });
+  });
+
   describe("Require Sharia Compliant", function () {
     it("Should not revert for compliant coin", async function () {
       // Register BTC first
-      await shariaCompliance.registerShariaCoin("BTC", "Bitcoin", "BTC", "Test");
+      await shariaCompliance.registerShariaCoin("BTC", "Bitcoin", "BTC", ethers.ZeroAddress, "Test", 0);
       
       await expect(
         shariaCompliance.requireShariaCompliant("BTC")
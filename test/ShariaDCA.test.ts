@@ -0,0 +1,1008 @@
+import { expect } from "chai";
+import { ethers } from "hardhat";
+import { time } from "@nomicfoundation/hardhat-network-helpers";
+import { ShariaCompliance, ShariaDCA, MockERC20 } from "../typechain-types";
+import { SignerWithAddress } from "@nomicfoundation/hardhat-ethers/signers";
+
+describe("ShariaDCA", function () {
+  let shariaCompliance: ShariaCompliance;
+  let shariaDCA: ShariaDCA;
+  let usdc: MockERC20;
+  let btc: MockERC20;
+  let owner: SignerWithAddress;
+  let user: SignerWithAddress;
+
+  const AMOUNT_PER_INTERVAL = ethers.parseEther("10");
+  const HOUR = 3600;
+
+  beforeEach(async function () {
+    [owner, user] = await ethers.getSigners();
+
+    const MockERC20 = await ethers.getContractFactory("MockERC20");
+    usdc = await MockERC20.deploy("USD Coin", "USDC", 18);
+    btc = await MockERC20.deploy("Bitcoin", "BTC", 18);
+    const weth = await MockERC20.deploy("Wrapped DEV", "WDEV", 18);
+
+    const ShariaCompliance = await ethers.getContractFactory("ShariaCompliance");
+    shariaCompliance = await ShariaCompliance.deploy();
+    await shariaCompliance.registerShariaCoin("USDC", "USD Coin", "USDC", await usdc.getAddress(), "Test", 0);
+    await shariaCompliance.registerShariaCoin("BTC", "Bitcoin", "BTC", await btc.getAddress(), "Test", 0);
+
+    // AMM with a USDC/BTC pool so orders can execute
+    const SimpleFactory = await ethers.getContractFactory("SimpleFactory");
+    const factory = await SimpleFactory.deploy();
+    const SimpleRouter = await ethers.getContractFactory("SimpleRouter");
+    const router = await SimpleRouter.deploy(await factory.getAddress(), await weth.getAddress());
+
+    await factory.createPair(await usdc.getAddress(), await btc.getAddress());
+    const liquidity = ethers.parseEther("1000000");
+    await usdc.mint(owner.address, liquidity);
+    await btc.mint(owner.address, liquidity);
+    await usdc.approve(await router.getAddress(), liquidity);
+    await btc.approve(await router.getAddress(), liquidity);
+    await router.addLiquidity(
+      await usdc.getAddress(),
+      await btc.getAddress(),
+      liquidity,
+      liquidity,
+      0,
+      0,
+      owner.address,
+      (await time.latest()) + HOUR
+    );
+
+    const ShariaDCA = await ethers.getContractFactory("ShariaDCA");
+    shariaDCA = await ShariaDCA.deploy(
+      await shariaCompliance.getAddress(),
+      await router.getAddress(),
+      await factory.getAddress(),
+      await weth.getAddress(),
+      6,
+      2
+    );
+
+    await usdc.mint(user.address, ethers.parseEther("1000"));
+    await usdc.connect(user).approve(await shariaDCA.getAddress(), ethers.MaxUint256);
+  });
+
+  async function createOrder(totalIntervals: number) {
+    await shariaDCA
+      .connect(user)
+      .createDCAOrderWithToken(
+        await usdc.getAddress(),
+        await btc.getAddress(),
+        AMOUNT_PER_INTERVAL,
+        HOUR,
+        totalIntervals,
+        ""
+      );
+    return (await shariaDCA.nextOrderId()) - 1n;
+  }
+
+  async function executeWhenReady(orderId: bigint) {
+    const order = await shariaDCA.getDCAOrder(orderId);
+    await time.increaseTo(order.nextExecutionTime);
+    await shariaDCA.executeDCAOrder(orderId);
+  }
+
+  describe("Create Order", function () {
+    it("Should reject schedules whose total commitment overflows", async function () {
+      await expect(
+        shariaDCA
+          .connect(user)
+          .createDCAOrderWithToken(
+            await usdc.getAddress(),
+            await btc.getAddress(),
+            ethers.MaxUint256 / 2n + 1n,
+            HOUR,
+            2,
+            ""
+          )
+      ).to.be.revertedWithCustomError(shariaDCA, "Overflow");
+    });
+
+    it("Should reject intervals outside MIN_INTERVAL and MAX_INTERVAL", async function () {
+      const usdcAddress = await usdc.getAddress();
+      const btcAddress = await btc.getAddress();
+      const maxInterval = await shariaDCA.MAX_INTERVAL();
+
+      await expect(
+        shariaDCA.connect(user).createDCAOrderWithToken(usdcAddress, btcAddress, AMOUNT_PER_INTERVAL, 0, 2, "")
+      ).to.be.revertedWithCustomError(shariaDCA, "InvalidInterval");
+      await expect(
+        shariaDCA
+          .connect(user)
+          .createDCAOrderWithToken(usdcAddress, btcAddress, AMOUNT_PER_INTERVAL, maxInterval + 1n, 2, "")
+      ).to.be.revertedWithCustomError(shariaDCA, "InvalidInterval");
+      await expect(
+        shariaDCA
+          .connect(user)
+          .createDCAOrderWithDEV(btcAddress, AMOUNT_PER_INTERVAL, 0, 2, "", { value: AMOUNT_PER_INTERVAL * 2n })
+      ).to.be.revertedWithCustomError(shariaDCA, "InvalidInterval");
+    });
+  });
+
+  describe("Default Target Token", function () {
+    it("Should create token- and DEV-funded orders into the default", async function () {
+      await expect(shariaDCA.connect(user).setDefaultTargetToken(await btc.getAddress()))
+        .to.emit(shariaDCA, "DefaultTargetTokenSet")
+        .withArgs(user.address, await btc.getAddress());
+
+      await shariaDCA
+        .connect(user)
+        .createDCAOrderWithTokenDefault(await usdc.getAddress(), AMOUNT_PER_INTERVAL, HOUR, 2, "");
+      const tokenOrder = await shariaDCA.getDCAOrder((await shariaDCA.nextOrderId()) - 1n);
+
+      await shariaDCA
+        .connect(user)
+        .createDCAOrderWithDEVDefault(AMOUNT_PER_INTERVAL, HOUR, 2, "", { value: AMOUNT_PER_INTERVAL * 2n });
+      const devOrder = await shariaDCA.getDCAOrder((await shariaDCA.nextOrderId()) - 1n);
+
+      expect(tokenOrder.sourceToken).to.equal(await usdc.getAddress());
+      expect(tokenOrder.targetToken).to.equal(await btc.getAddress());
+      expect(devOrder.sourceToken).to.equal(ethers.ZeroAddress);
+      expect(devOrder.targetToken).to.equal(await btc.getAddress());
+    });
+
+    it("Should revert with NoDefaultToken when none is set or it was cleared", async function () {
+      await expect(
+        shariaDCA.connect(user).createDCAOrderWithDEVDefault(AMOUNT_PER_INTERVAL, HOUR, 2, "", { value: AMOUNT_PER_INTERVAL * 2n })
+      ).to.be.revertedWithCustomError(shariaDCA, "NoDefaultToken");
+
+      await shariaDCA.connect(user).setDefaultTargetToken(await btc.getAddress());
+      await shariaDCA.connect(user).setDefaultTargetToken(ethers.ZeroAddress);
+
+      expect(await shariaDCA.getDefaultTargetToken(user.address)).to.equal(ethers.ZeroAddress);
+      await expect(
+        shariaDCA.connect(user).createDCAOrderWithTokenDefault(await usdc.getAddress(), AMOUNT_PER_INTERVAL, HOUR, 2, "")
+      ).to.be.revertedWithCustomError(shariaDCA, "NoDefaultToken");
+    });
+
+    it("Should reject unregistered and non-compliant tokens", async function () {
+      await expect(
+        shariaDCA.connect(user).setDefaultTargetToken(user.address)
+      ).to.be.revertedWithCustomError(shariaDCA, "TokenNotRegistered");
+
+      await shariaCompliance.updateComplianceStatus("BTC", false, "Under review");
+      await expect(
+        shariaDCA.connect(user).setDefaultTargetToken(await btc.getAddress())
+      ).to.be.revertedWithCustomError(shariaCompliance, "NotShariaCompliant");
+    });
+  });
+
+  describe("Migrate Account", function () {
+    let newAccount: SignerWithAddress;
+
+    beforeEach(async function () {
+      [, , newAccount] = await ethers.getSigners();
+      await usdc.mint(newAccount.address, ethers.parseEther("1000"));
+      await usdc.connect(newAccount).approve(await shariaDCA.getAddress(), ethers.MaxUint256);
+    });
+
+    it("Should merge orders into the destination and re-own them", async function () {
+      const migrated = await createOrder(2);
+      await shariaDCA
+        .connect(newAccount)
+        .createDCAOrderWithToken(await usdc.getAddress(), await btc.getAddress(), AMOUNT_PER_INTERVAL, HOUR, 2, "");
+      const existing = (await shariaDCA.nextOrderId()) - 1n;
+
+      await expect(shariaDCA.migrateAccount(user.address, newAccount.address))
+        .to.emit(shariaDCA, "AccountMigrated")
+        .withArgs(user.address, newAccount.address, 1);
+
+      expect(await shariaDCA.getUserOrders(newAccount.address)).to.deep.equal([existing, migrated]);
+      expect(await shariaDCA.getUserOrders(user.address)).to.deep.equal([]);
+      expect((await shariaDCA.getDCAOrder(migrated)).owner).to.equal(newAccount.address);
+    });
+
+    it("Should refund the new owner on cancel and lock out the old owner", async function () {
+      const orderId = await createOrder(2);
+      await shariaDCA.migrateAccount(user.address, newAccount.address);
+
+      await expect(
+        shariaDCA.connect(user).cancelDCAOrder(orderId)
+      ).to.be.revertedWithCustomError(shariaDCA, "Unauthorized");
+
+      const balanceBefore = await usdc.balanceOf(newAccount.address);
+      await shariaDCA.connect(newAccount).cancelDCAOrder(orderId);
+      expect(await usdc.balanceOf(newAccount.address)).to.equal(balanceBefore + AMOUNT_PER_INTERVAL * 2n);
+    });
+
+    it("Should carry over the default target token and referrer", async function () {
+      await shariaDCA.connect(user).setDefaultTargetToken(await btc.getAddress());
+      await shariaDCA.connect(user).setReferrer(owner.address);
+
+      await shariaDCA.migrateAccount(user.address, newAccount.address);
+
+      expect(await shariaDCA.getDefaultTargetToken(newAccount.address)).to.equal(await btc.getAddress());
+      expect(await shariaDCA.referrer(newAccount.address)).to.equal(owner.address);
+      expect(await shariaDCA.getDefaultTargetToken(user.address)).to.equal(ethers.ZeroAddress);
+      expect(await shariaDCA.referrer(user.address)).to.equal(ethers.ZeroAddress);
+    });
+
+    it("Should move cost basis to the new address", async function () {
+      const orderId = await createOrder(2);
+      await executeWhenReady(orderId);
+
+      await shariaDCA.migrateAccount(user.address, newAccount.address);
+
+      const btcAddress = await btc.getAddress();
+      const usdcAddress = await usdc.getAddress();
+      expect(await shariaDCA.getUserCostBasis(newAccount.address, btcAddress, usdcAddress)).to.equal(
+        AMOUNT_PER_INTERVAL
+      );
+      expect(await shariaDCA.getUserCostBasis(user.address, btcAddress, usdcAddress)).to.equal(0);
+
+      await executeWhenReady(orderId);
+      expect(await shariaDCA.getUserCostBasis(newAccount.address, btcAddress, usdcAddress)).to.equal(
+        AMOUNT_PER_INTERVAL * 2n
+      );
+    });
+
+    it("Should move referred volume earned by the old address", async function () {
+      const [, , , referred] = await ethers.getSigners();
+      await usdc.mint(referred.address, ethers.parseEther("1000"));
+      await usdc.connect(referred).approve(await shariaDCA.getAddress(), ethers.MaxUint256);
+      await shariaDCA.connect(referred).setReferrer(user.address);
+      await shariaDCA
+        .connect(referred)
+        .createDCAOrderWithToken(await usdc.getAddress(), await btc.getAddress(), AMOUNT_PER_INTERVAL, HOUR, 2, "");
+      await executeWhenReady((await shariaDCA.nextOrderId()) - 1n);
+
+      await shariaDCA.migrateAccount(user.address, newAccount.address);
+
+      const usdcAddress = await usdc.getAddress();
+      expect(await shariaDCA.getReferredVolume(newAccount.address, usdcAddress)).to.equal(AMOUNT_PER_INTERVAL);
+      expect(await shariaDCA.getReferredVolume(user.address, usdcAddress)).to.equal(0);
+    });
+
+    it("Should reject invalid migrations and non-owners", async function () {
+      await expect(
+        shariaDCA.migrateAccount(user.address, user.address)
+      ).to.be.revertedWithCustomError(shariaDCA, "InvalidMigration");
+      await expect(
+        shariaDCA.migrateAccount(user.address, ethers.ZeroAddress)
+      ).to.be.revertedWithCustomError(shariaDCA, "InvalidMigration");
+      await expect(
+        shariaDCA.connect(user).migrateAccount(user.address, newAccount.address)
+      ).to.be.revertedWithCustomError(shariaDCA, "OwnableUnauthorizedAccount");
+    });
+  });
+
+  describe("Terminate", function () {
+    it("Should only be callable once, by the owner", async function () {
+      await expect(shariaDCA.connect(user).terminate()).to.be.revertedWithCustomError(
+        shariaDCA,
+        "OwnableUnauthorizedAccount"
+      );
+
+      await expect(shariaDCA.terminate()).to.emit(shariaDCA, "DCATerminated").withArgs(owner.address);
+      await expect(shariaDCA.terminate()).to.be.revertedWithCustomError(shariaDCA, "ContractTerminated");
+    });
+
+    it("Should block everything that invests or adds escrow", async function () {
+      const orderId = await createOrder(3);
+      await time.increaseTo((await shariaDCA.getDCAOrder(orderId)).nextExecutionTime);
+      await shariaDCA.terminate();
+
+      await expect(createOrder(2)).to.be.revertedWithCustomError(shariaDCA, "ContractTerminated");
+      await expect(
+        shariaDCA
+          .connect(user)
+          .createDCAOrderWithDEV(await btc.getAddress(), AMOUNT_PER_INTERVAL, HOUR, 2, "", { value: AMOUNT_PER_INTERVAL * 2n })
+      ).to.be.revertedWithCustomError(shariaDCA, "ContractTerminated");
+      await expect(shariaDCA.executeDCAOrder(orderId)).to.be.revertedWithCustomError(shariaDCA, "ContractTerminated");
+      await expect(
+        shariaDCA.connect(user).modifyDCAOrder(orderId, AMOUNT_PER_INTERVAL, HOUR)
+      ).to.be.revertedWithCustomError(shariaDCA, "ContractTerminated");
+      await expect(
+        shariaDCA.connect(user).resumeDCAOrder(orderId)
+      ).to.be.revertedWithCustomError(shariaDCA, "ContractTerminated");
+      await expect(
+        shariaDCA.connect(user).setDCATotalIntervals(orderId, 4)
+      ).to.be.revertedWithCustomError(shariaDCA, "ContractTerminated");
+
+      const [upkeepNeeded] = await shariaDCA.checkUpkeep("0x");
+      expect(upkeepNeeded).to.be.false;
+    });
+
+    it("Should still let users lower the cap and cancel to recover escrow", async function () {
+      const orderId = await createOrder(3);
+      await shariaDCA.terminate();
+
+      await expect(
+        shariaDCA.connect(user).setDCATotalIntervals(orderId, 2)
+      ).to.changeTokenBalances(usdc, [user, shariaDCA], [AMOUNT_PER_INTERVAL, -AMOUNT_PER_INTERVAL]);
+      await expect(
+        shariaDCA.connect(user).cancelDCAOrder(orderId)
+      ).to.changeTokenBalances(usdc, [user, shariaDCA], [AMOUNT_PER_INTERVAL * 2n, -AMOUNT_PER_INTERVAL * 2n]);
+    });
+  });
+
+  describe("Paused Target", function () {
+    it("Should reject new orders into a paused coin", async function () {
+      await shariaCompliance.setCoinPaused("BTC", true, "Under re-review");
+
+      await expect(createOrder(2)).to.be.revertedWithCustomError(shariaCompliance, "CoinPaused");
+      await expect(
+        shariaDCA
+          .connect(user)
+          .createDCAOrderWithDEV(await btc.getAddress(), AMOUNT_PER_INTERVAL, HOUR, 2, "", { value: AMOUNT_PER_INTERVAL * 2n })
+      ).to.be.revertedWithCustomError(shariaCompliance, "CoinPaused");
+    });
+
+    it("Should stop existing orders from buying without counting failures", async function () {
+      const orderId = await createOrder(2);
+      await shariaDCA.setMaxConsecutiveFailures(1);
+      await time.increaseTo((await shariaDCA.getDCAOrder(orderId)).nextExecutionTime);
+      await shariaCompliance.setCoinPaused("BTC", true, "Under re-review");
+
+      await expect(shariaDCA.executeDCAOrder(orderId)).to.be.revertedWithCustomError(shariaCompliance, "CoinPaused");
+      const [outcome] = await shariaDCA.tryExecuteDCAOrder.staticCall(orderId);
+      expect(outcome).to.equal(6n); // NotInvestable
+
+      await shariaDCA.executeDCAOrdersBatch([orderId]);
+      expect(await shariaDCA.consecutiveFailures(orderId)).to.equal(0);
+      expect((await shariaDCA.getDCAOrder(orderId)).isActive).to.be.true;
+
+      await shariaCompliance.setCoinPaused("BTC", false, "");
+      await shariaDCA.executeDCAOrder(orderId);
+      expect((await shariaDCA.getDCAOrder(orderId)).intervalsCompleted).to.equal(1);
+    });
+
+    it("Should leave orders into a paused coin out of the due views", async function () {
+      const orderId = await createOrder(2);
+      await time.increaseTo((await shariaDCA.getDCAOrder(orderId)).nextExecutionTime);
+
+      expect((await shariaDCA.checkUpkeep("0x"))[0]).to.be.true;
+      expect(await shariaDCA.getUserExecutableOrders(user.address)).to.deep.equal([orderId]);
+      expect(await shariaDCA.getDueOrdersSorted(0, 10, 10)).to.deep.equal([orderId]);
+
+      await shariaCompliance.setCoinPaused("BTC", true, "Under re-review");
+
+      const [upkeepNeeded] = await shariaDCA.checkUpkeep("0x");
+      expect(upkeepNeeded).to.be.false;
+      expect(await shariaDCA.getUserExecutableOrders(user.address)).to.deep.equal([]);
+      expect(await shariaDCA.getDueOrdersSorted(0, 10, 10)).to.deep.equal([]);
+    });
+  });
+
+  describe("Lapsed Verification", function () {
+    it("Should stop existing orders from buying once verification lapses", async function () {
+      const orderId = await createOrder(2);
+      await shariaDCA.setMaxConsecutiveFailures(1);
+      const dueTime = (await shariaDCA.getDCAOrder(orderId)).nextExecutionTime;
+      await shariaCompliance.renewVerification("BTC", dueTime);
+      await time.increaseTo(dueTime);
+
+      await expect(shariaDCA.executeDCAOrder(orderId)).to.be.revertedWithCustomError(
+        shariaCompliance,
+        "NotShariaCompliant"
+      );
+      const [outcome] = await shariaDCA.tryExecuteDCAOrder.staticCall(orderId);
+      expect(outcome).to.equal(6n); // NotInvestable
+
+      await shariaDCA.executeDCAOrdersBatch([orderId]);
+      expect(await shariaDCA.consecutiveFailures(orderId)).to.equal(0);
+      expect((await shariaDCA.getDCAOrder(orderId)).isActive).to.be.true;
+
+      await shariaCompliance.renewVerification("BTC", 0);
+      await shariaDCA.executeDCAOrder(orderId);
+      expect((await shariaDCA.getDCAOrder(orderId)).intervalsCompleted).to.equal(1);
+    });
+  });
+
+  describe("Active Order Count", function () {
+    it("Should stay consistent across create, cancel and complete", async function () {
+      expect(await shariaDCA.getActiveOrderCount()).to.equal(0);
+
+      const completing = await createOrder(1);
+      const cancelled = await createOrder(3);
+      expect(await shariaDCA.getActiveOrderCount()).to.equal(2);
+
+      await shariaDCA.connect(user).cancelDCAOrder(cancelled);
+      expect(await shariaDCA.getActiveOrderCount()).to.equal(1);
+
+      await executeWhenReady(completing);
+      expect((await shariaDCA.getDCAOrder(completing)).isActive).to.be.false;
+      expect(await shariaDCA.getActiveOrderCount()).to.equal(0);
+    });
+  });
+
+  describe("Execute Order", function () {
+    it("Should return amounts and progress of the execution", async function () {
+      const orderId = await createOrder(2);
+      const order = await shariaDCA.getDCAOrder(orderId);
+      await time.increaseTo(order.nextExecutionTime);
+
+      const result = await shariaDCA.executeDCAOrder.staticCall(orderId);
+
+      expect(result.amountIn).to.equal(AMOUNT_PER_INTERVAL);
+      expect(result.amountOut).to.be.greaterThan(0);
+      expect(result.intervalsCompleted).to.equal(1);
+      expect(result.isActive).to.be.true;
+    });
+
+    it("Should accumulate the user's cost basis across buys", async function () {
+      const first = await createOrder(2);
+      const second = await createOrder(1);
+
+      // Both orders share their first execution time
+      await time.increaseTo((await shariaDCA.getDCAOrder(second)).nextExecutionTime);
+      await shariaDCA.executeDCAOrder(first);
+      await shariaDCA.executeDCAOrder(second);
+      await executeWhenReady(first);
+
+      expect(
+        await shariaDCA.getUserCostBasis(user.address, await btc.getAddress(), await usdc.getAddress())
+      ).to.equal(AMOUNT_PER_INTERVAL * 3n);
+      expect(
+        await shariaDCA.getUserCostBasis(user.address, await btc.getAddress(), ethers.ZeroAddress)
+      ).to.equal(0);
+    });
+
+    it("Should accumulate executed volume per source token", async function () {
+      const orderId = await createOrder(2);
+
+      await executeWhenReady(orderId);
+      await executeWhenReady(orderId);
+
+      expect(await shariaDCA.getTotalDCAVolume(await usdc.getAddress())).to.equal(AMOUNT_PER_INTERVAL * 2n);
+      expect(await shariaDCA.getTotalDCAVolume(ethers.ZeroAddress)).to.equal(0);
+    });
+  });
+
+  describe("Try Execute Order", function () {
+    const Outcome = { Executed: 0n, NotFound: 1n, Inactive: 2n, NotReady: 3n, NotInvestable: 6n };
+
+    it("Should report why an order was skipped instead of reverting", async function () {
+      const orderId = await createOrder(2);
+
+      const [notReady] = await shariaDCA.tryExecuteDCAOrder.staticCall(orderId);
+      const [notFound] = await shariaDCA.tryExecuteDCAOrder.staticCall(999);
+      await shariaDCA.connect(user).cancelDCAOrder(orderId);
+      const [inactive] = await shariaDCA.tryExecuteDCAOrder.staticCall(orderId);
+
+      expect(notReady).to.equal(Outcome.NotReady);
+      expect(notFound).to.equal(Outcome.NotFound);
+      expect(inactive).to.equal(Outcome.Inactive);
+    });
+
+    it("Should execute a ready order", async function () {
+      const orderId = await createOrder(2);
+      await time.increaseTo((await shariaDCA.getDCAOrder(orderId)).nextExecutionTime);
+
+      const [outcome, amountOut] = await shariaDCA.tryExecuteDCAOrder.staticCall(orderId);
+      expect(outcome).to.equal(Outcome.Executed);
+      expect(amountOut).to.be.greaterThan(0);
+
+      await shariaDCA.tryExecuteDCAOrder(orderId);
+      expect((await shariaDCA.getDCAOrder(orderId)).intervalsCompleted).to.equal(1);
+    });
+  });
+
+  describe("All Orders", function () {
+    it("Should page through every order for the owner", async function () {
+      const first = await createOrder(2);
+      const second = await createOrder(2);
+      const third = await createOrder(2);
+      await shariaDCA.connect(user).cancelDCAOrder(second);
+
+      const page = await shariaDCA.getAllDCAOrders(1, 5);
+
+      expect(page.map((order) => order.id)).to.deep.equal([second, third]);
+      expect(page[0].isActive).to.be.false;
+      expect((await shariaDCA.getAllDCAOrders(0, 1))[0].id).to.equal(first);
+      expect(await shariaDCA.getAllDCAOrders(3, 5)).to.deep.equal([]);
+    });
+
+    it("Should not allow non-owner to list all orders", async function () {
+      await expect(
+        shariaDCA.connect(user).getAllDCAOrders(0, 10)
+      ).to.be.revertedWithCustomError(shariaDCA, "OwnableUnauthorizedAccount");
+    });
+  });
+
+  describe("Orders By Token", function () {
+    let ethAddress: string;
+
+    beforeEach(async function () {
+      const MockERC20 = await ethers.getContractFactory("MockERC20");
+      const eth = await MockERC20.deploy("Ethereum", "ETH", 18);
+      ethAddress = await eth.getAddress();
+      await shariaCompliance.registerShariaCoin("ETH", "Ethereum", "ETH", ethAddress, "Test", 0);
+    });
+
+    it("Should list active orders for a token within a range of order IDs", async function () {
+      const first = await createOrder(2);
+      await shariaDCA
+        .connect(user)
+        .createDCAOrderWithToken(await usdc.getAddress(), ethAddress, AMOUNT_PER_INTERVAL, HOUR, 2, "");
+      const cancelled = await createOrder(2);
+      const last = await createOrder(2);
+      await shariaDCA.connect(user).cancelDCAOrder(cancelled);
+      const btcAddress = await btc.getAddress();
+
+      const ids = async (offset: number, maxScan: number) =>
+        (await shariaDCA.getDCAOrdersByToken(btcAddress, offset, maxScan)).map((order) => order.id);
+
+      expect(await ids(0, 10)).to.deep.equal([first, last]);
+      expect(await ids(0, 3)).to.deep.equal([first]);
+      expect(await ids(3, 1)).to.deep.equal([last]);
+      expect(await ids(4, 10)).to.deep.equal([]);
+    });
+
+    it("Should total demand per source token within a range of order IDs", async function () {
+      const usdcAddress = await usdc.getAddress();
+      const btcAddress = await btc.getAddress();
+      await createOrder(2);
+      await shariaDCA
+        .connect(user)
+        .createDCAOrderWithDEV(btcAddress, AMOUNT_PER_INTERVAL, HOUR, 2, "", { value: AMOUNT_PER_INTERVAL * 2n });
+      await shariaDCA
+        .connect(user)
+        .createDCAOrderWithToken(usdcAddress, btcAddress, AMOUNT_PER_INTERVAL * 3n, HOUR, 2, "");
+
+      expect(await shariaDCA.getTokenDCADemand(btcAddress, usdcAddress, 0, 10)).to.deep.equal([
+        2n,
+        AMOUNT_PER_INTERVAL * 4n,
+      ]);
+      expect(await shariaDCA.getTokenDCADemand(btcAddress, usdcAddress, 0, 2)).to.deep.equal([
+        1n,
+        AMOUNT_PER_INTERVAL,
+      ]);
+      expect(await shariaDCA.getTokenDCADemand(btcAddress, ethers.ZeroAddress, 1, 1)).to.deep.equal([
+        1n,
+        AMOUNT_PER_INTERVAL,
+      ]);
+      expect(await shariaDCA.getTokenDCADemand(btcAddress, usdcAddress, 3, 10)).to.deep.equal([0n, 0n]);
+    });
+  });
+
+  describe("User Executable Orders", function () {
+    it("Should list only the user's orders that are due", async function () {
+      const due = await createOrder(2);
+      await time.increaseTo((await shariaDCA.getDCAOrder(due)).nextExecutionTime);
+      await createOrder(2); // not due until the next hour boundary
+      const cancelled = await createOrder(2);
+      await shariaDCA.connect(user).cancelDCAOrder(cancelled);
+
+      const ready = await shariaDCA.getUserExecutableOrders(user.address);
+
+      expect(ready).to.deep.equal([due]);
+      expect(await shariaDCA.getUserExecutableOrders(owner.address)).to.deep.equal([]);
+    });
+  });
+
+  describe("Next User Execution", function () {
+    it("Should return the soonest-due active order", async function () {
+      // A daily order that has already run once is next due a day out
+      await shariaDCA
+        .connect(user)
+        .createDCAOrderWithToken(await usdc.getAddress(), await btc.getAddress(), AMOUNT_PER_INTERVAL, HOUR * 24, 2, "");
+      const later = (await shariaDCA.nextOrderId()) - 1n;
+      await executeWhenReady(later);
+      const sooner = await createOrder(2);
+      const soonerOrder = await shariaDCA.getDCAOrder(sooner);
+      expect(soonerOrder.nextExecutionTime).to.be.lessThan((await shariaDCA.getDCAOrder(later)).nextExecutionTime);
+
+      expect(await shariaDCA.getNextUserExecution(user.address)).to.deep.equal([sooner, soonerOrder.nextExecutionTime]);
+
+      await shariaDCA.connect(user).cancelDCAOrder(sooner);
+      await shariaDCA.connect(user).cancelDCAOrder(later);
+      expect(await shariaDCA.getNextUserExecution(user.address)).to.deep.equal([0n, 0n]);
+    });
+  });
+
+  describe("Due Orders Sorted", function () {
+    it("Should list the most overdue orders first", async function () {
+      const first = await createOrder(3);
+      const second = await createOrder(3);
+
+      // Running the first order pushes it behind the second
+      await executeWhenReady(first);
+      await time.increaseTo((await shariaDCA.getDCAOrder(first)).nextExecutionTime);
+
+      expect(await shariaDCA.getDueOrdersSorted(0, 10, 10)).to.deep.equal([second, first]);
+      expect(await shariaDCA.getDueOrdersSorted(0, 10, 1)).to.deep.equal([second]);
+    });
+
+    it("Should keep the most overdue orders when the limit is smaller than the backlog", async function () {
+      const first = await createOrder(3);
+      const second = await createOrder(3);
+      const third = await createOrder(3);
+
+      // first and third run once, leaving second the most overdue and first ahead of third on a tie
+      await time.increaseTo((await shariaDCA.getDCAOrder(first)).nextExecutionTime);
+      await shariaDCA.executeDCAOrdersBatch([first, third]);
+      await time.increaseTo((await shariaDCA.getDCAOrder(third)).nextExecutionTime);
+
+      expect(await shariaDCA.getDueOrdersSorted(0, 10, 2)).to.deep.equal([second, first]);
+      expect(await shariaDCA.getDueOrdersSorted(0, 10, 3)).to.deep.equal([second, first, third]);
+    });
+
+    it("Should only consider orders in the scanned range", async function () {
+      const first = await createOrder(3);
+      const second = await createOrder(3);
+      const third = await createOrder(3);
+      await time.increaseTo((await shariaDCA.getDCAOrder(third)).nextExecutionTime);
+
+      expect(await shariaDCA.getDueOrdersSorted(1, 1, 10)).to.deep.equal([second]);
+      expect(await shariaDCA.getDueOrdersSorted(1, 10, 10)).to.deep.equal([second, third]);
+      expect(await shariaDCA.getDueOrdersSorted(0, 2, 10)).to.deep.equal([first, second]);
+      expect(await shariaDCA.getDueOrdersSorted(3, 10, 10)).to.deep.equal([]);
+    });
+  });
+
+  describe("Batch Execution", function () {
+    it("Should execute due orders and skip failing ones", async function () {
+      // ETH has no pool, so swaps into it always fail
+      const MockERC20 = await ethers.getContractFactory("MockERC20");
+      const eth = await MockERC20.deploy("Ethereum", "ETH", 18);
+      await shariaCompliance.registerShariaCoin("ETH", "Ethereum", "ETH", await eth.getAddress(), "Test", 0);
+
+      const good = await createOrder(2);
+      await shariaDCA
+        .connect(user)
+        .createDCAOrderWithToken(await usdc.getAddress(), await eth.getAddress(), AMOUNT_PER_INTERVAL, HOUR, 2, "");
+      const failing = (await shariaDCA.nextOrderId()) - 1n;
+      await time.increaseTo((await shariaDCA.getDCAOrder(failing)).nextExecutionTime);
+
+      const results = await shariaDCA.executeDCAOrdersBatch.staticCall([good, failing]);
+      expect(results).to.deep.equal([true, false]);
+
+      await shariaDCA.executeDCAOrdersBatch([good, failing]);
+      expect((await shariaDCA.getDCAOrder(good)).intervalsCompleted).to.equal(1);
+      expect((await shariaDCA.getDCAOrder(failing)).intervalsCompleted).to.equal(0);
+      expect(await shariaDCA.consecutiveFailures(failing)).to.equal(1);
+    });
+
+    it("Should reject batches over the size limit", async function () {
+      const orderIds = Array.from({ length: 51 }, (_, i) => i + 1);
+
+      await expect(
+        shariaDCA.executeDCAOrdersBatch(orderIds)
+      ).to.be.revertedWithCustomError(shariaDCA, "BatchTooLarge");
+    });
+  });
+
+  describe("Referrals", function () {
+    it("Should attribute executed volume to the referrer", async function () {
+      await expect(shariaDCA.connect(user).setReferrer(owner.address))
+        .to.emit(shariaDCA, "ReferrerSet")
+        .withArgs(user.address, owner.address);
+
+      const orderId = await createOrder(2);
+      await executeWhenReady(orderId);
+
+      expect(await shariaDCA.getReferredVolume(owner.address, await usdc.getAddress())).to.equal(AMOUNT_PER_INTERVAL);
+    });
+
+    it("Should reject self-referral and changing the referrer", async function () {
+      await expect(
+        shariaDCA.connect(user).setReferrer(user.address)
+      ).to.be.revertedWithCustomError(shariaDCA, "InvalidReferrer");
+
+      await shariaDCA.connect(user).setReferrer(owner.address);
+      const [, , other] = await ethers.getSigners();
+
+      await expect(
+        shariaDCA.connect(user).setReferrer(other.address)
+      ).to.be.revertedWithCustomError(shariaDCA, "ReferrerAlreadySet");
+    });
+  });
+
+  describe("Prune User Orders", function () {
+    it("Should drop finished orders from the user's list but keep their records", async function () {
+      const completed = await createOrder(1);
+      const active = await createOrder(2);
+      const cancelled = await createOrder(2);
+      await executeWhenReady(completed);
+      await shariaDCA.connect(user).cancelDCAOrder(cancelled);
+
+      await expect(shariaDCA.connect(user).pruneUserOrders())
+        .to.emit(shariaDCA, "UserOrdersPruned")
+        .withArgs(user.address, 2);
+
+      expect(await shariaDCA.getUserOrders(user.address)).to.deep.equal([active]);
+      expect((await shariaDCA.getDCAOrder(completed)).exists).to.be.true;
+    });
+  });
+
+  describe("Recommendations", function () {
+    it("Should let the owner publish suggested defaults", async function () {
+      await expect(shariaDCA.setDCARecommendations(AMOUNT_PER_INTERVAL, HOUR * 24))
+        .to.emit(shariaDCA, "DCARecommendationsSet")
+        .withArgs(AMOUNT_PER_INTERVAL, HOUR * 24);
+
+      expect(await shariaDCA.getDCARecommendations()).to.deep.equal([AMOUNT_PER_INTERVAL, BigInt(HOUR * 24)]);
+    });
+
+    it("Should let the owner withdraw the suggestion with (0, 0)", async function () {
+      await shariaDCA.setDCARecommendations(AMOUNT_PER_INTERVAL, HOUR * 24);
+
+      await expect(shariaDCA.setDCARecommendations(0, 0))
+        .to.emit(shariaDCA, "DCARecommendationsSet")
+        .withArgs(0, 0);
+
+      expect(await shariaDCA.getDCARecommendations()).to.deep.equal([0n, 0n]);
+    });
+
+    it("Should reject invalid suggestions and non-owners", async function () {
+      await expect(
+        shariaDCA.setDCARecommendations(AMOUNT_PER_INTERVAL, 60)
+      ).to.be.revertedWithCustomError(shariaDCA, "InvalidInterval");
+      await expect(
+        shariaDCA.setDCARecommendations(0, HOUR)
+      ).to.be.revertedWithCustomError(shariaDCA, "InvalidAmount");
+      await expect(
+        shariaDCA.setDCARecommendations(AMOUNT_PER_INTERVAL, 0)
+      ).to.be.revertedWithCustomError(shariaDCA, "InvalidInterval");
+      await expect(
+        shariaDCA.connect(user).setDCARecommendations(AMOUNT_PER_INTERVAL, HOUR)
+      ).to.be.revertedWithCustomError(shariaDCA, "OwnableUnauthorizedAccount");
+    });
+  });
+
+  describe("Preview Schedule", function () {
+    it("Should match the times an order actually executes at", async function () {
+      // Mid-hour, so previewing and creating a block apart round the same way
+      await time.increaseTo((Math.floor((await time.latest()) / HOUR) + 1) * HOUR + HOUR / 2);
+      const schedule = await shariaDCA.previewDCASchedule(HOUR, 3);
+      const orderId = await createOrder(3);
+
+      for (const expected of schedule) {
+        const order = await shariaDCA.getDCAOrder(orderId);
+        expect(order.nextExecutionTime).to.equal(expected);
+        await time.setNextBlockTimestamp(order.nextExecutionTime);
+        await shariaDCA.executeDCAOrder(orderId);
+      }
+    });
+
+    it("Should cap the number of entries", async function () {
+      expect((await shariaDCA.previewDCASchedule(HOUR, 100)).length).to.equal(52);
+    });
+  });
+
+  describe("Order Label", function () {
+    it("Should let the owner label an order", async function () {
+      const orderId = await createOrder(2);
+
+      await expect(shariaDCA.connect(user).setDCALabel(orderId, "retirement"))
+        .to.emit(shariaDCA, "DCAOrderLabelSet")
+        .withArgs(orderId, "retirement");
+
+      expect((await shariaDCA.getDCAOrder(orderId)).label).to.equal("retirement");
+    });
+
+    it("Should reject labels that are too long or set by non-owners", async function () {
+      const orderId = await createOrder(2);
+
+      await expect(
+        shariaDCA.connect(user).setDCALabel(orderId, "x".repeat(33))
+      ).to.be.revertedWithCustomError(shariaDCA, "LabelTooLong");
+      await expect(
+        shariaDCA.connect(owner).setDCALabel(orderId, "house fund")
+      ).to.be.revertedWithCustomError(shariaDCA, "Unauthorized");
+    });
+
+    it("Should set the label at creation and reject an overlong one", async function () {
+      const usdcAddress = await usdc.getAddress();
+      const btcAddress = await btc.getAddress();
+
+      await shariaDCA
+        .connect(user)
+        .createDCAOrderWithToken(usdcAddress, btcAddress, AMOUNT_PER_INTERVAL, HOUR, 2, "house fund");
+      const orderId = (await shariaDCA.nextOrderId()) - 1n;
+      expect((await shariaDCA.getDCAOrder(orderId)).label).to.equal("house fund");
+
+      await expect(
+        shariaDCA
+          .connect(user)
+          .createDCAOrderWithToken(usdcAddress, btcAddress, AMOUNT_PER_INTERVAL, HOUR, 2, "x".repeat(33))
+      ).to.be.revertedWithCustomError(shariaDCA, "LabelTooLong");
+    });
+  });
+
+  describe("Modify Order", function () {
+    it("Should pull and refund token escrow to match the new amount", async function () {
+      const orderId = await createOrder(2);
+      const dcaAddress = await shariaDCA.getAddress();
+
+      await expect(
+        shariaDCA.connect(user).modifyDCAOrder(orderId, AMOUNT_PER_INTERVAL * 3n, HOUR * 2)
+      ).to.changeTokenBalances(usdc, [user, shariaDCA], [-AMOUNT_PER_INTERVAL * 4n, AMOUNT_PER_INTERVAL * 4n]);
+
+      await expect(
+        shariaDCA.connect(user).modifyDCAOrder(orderId, AMOUNT_PER_INTERVAL, HOUR)
+      ).to.changeTokenBalances(usdc, [user, shariaDCA], [AMOUNT_PER_INTERVAL * 4n, -AMOUNT_PER_INTERVAL * 4n]);
+
+      await expect(shariaDCA.connect(user).modifyDCAOrder(orderId, AMOUNT_PER_INTERVAL, HOUR * 2))
+        .to.emit(shariaDCA, "DCAOrderModified")
+        .withArgs(orderId, AMOUNT_PER_INTERVAL, HOUR * 2, 2);
+
+      expect(await shariaDCA.getTotalEscrowed(await usdc.getAddress())).to.equal(await usdc.balanceOf(dcaAddress));
+    });
+
+    it("Should take a DEV top-up and refund the excess", async function () {
+      await shariaDCA
+        .connect(user)
+        .createDCAOrderWithDEV(await btc.getAddress(), AMOUNT_PER_INTERVAL, HOUR, 2, "", { value: AMOUNT_PER_INTERVAL * 2n });
+      const orderId = (await shariaDCA.nextOrderId()) - 1n;
+
+      // Doubling the amount over 2 intervals needs 2 more; the other 5 come back
+      await expect(
+        shariaDCA
+          .connect(user)
+          .modifyDCAOrder(orderId, AMOUNT_PER_INTERVAL * 2n, HOUR, { value: AMOUNT_PER_INTERVAL * 7n })
+      ).to.changeEtherBalances([user, shariaDCA], [-AMOUNT_PER_INTERVAL * 2n, AMOUNT_PER_INTERVAL * 2n]);
+
+      await expect(
+        shariaDCA.connect(user).modifyDCAOrder(orderId, AMOUNT_PER_INTERVAL * 2n, HOUR, { value: AMOUNT_PER_INTERVAL - 1n })
+      ).to.changeEtherBalances([user, shariaDCA], [0, 0]);
+      await expect(
+        shariaDCA.connect(user).modifyDCAOrder(orderId, AMOUNT_PER_INTERVAL * 3n, HOUR, { value: AMOUNT_PER_INTERVAL })
+      ).to.be.revertedWithCustomError(shariaDCA, "InsufficientDeposit");
+
+      expect(await shariaDCA.getTotalEscrowed(ethers.ZeroAddress)).to.equal(
+        await ethers.provider.getBalance(await shariaDCA.getAddress())
+      );
+    });
+
+    it("Should count intervals already run at their old amount", async function () {
+      const orderId = await createOrder(2);
+      await executeWhenReady(orderId);
+
+      await shariaDCA.connect(user).modifyDCAOrder(orderId, AMOUNT_PER_INTERVAL * 2n, HOUR);
+
+      expect(await shariaDCA.getTotalCommitment(orderId)).to.equal(AMOUNT_PER_INTERVAL * 3n);
+      expect(await shariaDCA.getRemainingCommitment(orderId)).to.equal(AMOUNT_PER_INTERVAL * 2n);
+
+      // Cancelling refunds the rest, leaving only what was invested
+      await shariaDCA.connect(user).cancelDCAOrder(orderId);
+
+      expect(await shariaDCA.getTotalCommitment(orderId)).to.equal(AMOUNT_PER_INTERVAL);
+      expect(await shariaDCA.getRemainingCommitment(orderId)).to.equal(0);
+    });
+
+    it("Should reject DEV sent with a token order, bad intervals and non-owners", async function () {
+      const orderId = await createOrder(2);
+
+      await expect(
+        shariaDCA.connect(user).modifyDCAOrder(orderId, AMOUNT_PER_INTERVAL, HOUR, { value: 1 })
+      ).to.be.revertedWithCustomError(shariaDCA, "InvalidAmount");
+      await expect(
+        shariaDCA.connect(user).modifyDCAOrder(orderId, AMOUNT_PER_INTERVAL, 60)
+      ).to.be.revertedWithCustomError(shariaDCA, "InvalidInterval");
+      await expect(
+        shariaDCA.connect(owner).modifyDCAOrder(orderId, AMOUNT_PER_INTERVAL, HOUR)
+      ).to.be.revertedWithCustomError(shariaDCA, "Unauthorized");
+    });
+  });
+
+  describe("Set Total Intervals", function () {
+    it("Should refund surplus escrow when lowering the cap", async function () {
+      const orderId = await createOrder(3);
+      const balanceBefore = await usdc.balanceOf(user.address);
+
+      await expect(shariaDCA.connect(user).setDCATotalIntervals(orderId, 1))
+        .to.emit(shariaDCA, "DCAOrderModified")
+        .withArgs(orderId, AMOUNT_PER_INTERVAL, HOUR, 1);
+
+      expect(await usdc.balanceOf(user.address)).to.equal(balanceBefore + AMOUNT_PER_INTERVAL * 2n);
+      expect((await shariaDCA.getDCAOrder(orderId)).isActive).to.be.true;
+    });
+
+    it("Should complete the order when the cap is at or below completed intervals", async function () {
+      const orderId = await createOrder(3);
+      await executeWhenReady(orderId);
+      const balanceBefore = await usdc.balanceOf(user.address);
+
+      await expect(shariaDCA.connect(user).setDCATotalIntervals(orderId, 0))
+        .to.emit(shariaDCA, "DCAOrderCompleted")
+        .withArgs(orderId, user.address, 1);
+
+      const order = await shariaDCA.getDCAOrder(orderId);
+      expect(order.isActive).to.be.false;
+      expect(order.totalIntervals).to.equal(1);
+      expect(await usdc.balanceOf(user.address)).to.equal(balanceBefore + AMOUNT_PER_INTERVAL * 2n);
+      expect(await shariaDCA.getActiveOrderCount()).to.equal(0);
+    });
+
+    it("Should pull extra escrow when raising the cap", async function () {
+      const orderId = await createOrder(1);
+      const balanceBefore = await usdc.balanceOf(user.address);
+
+      await shariaDCA.connect(user).setDCATotalIntervals(orderId, 3);
+
+      expect(await usdc.balanceOf(user.address)).to.equal(balanceBefore - AMOUNT_PER_INTERVAL * 2n);
+      expect(await shariaDCA.getRemainingCommitment(orderId)).to.equal(AMOUNT_PER_INTERVAL * 3n);
+    });
+  });
+
+  describe("Escrow Accounting", function () {
+    it("Should match the contract's token balance across create, execute and cancel", async function () {
+      const dcaAddress = await shariaDCA.getAddress();
+      const usdcAddress = await usdc.getAddress();
+
+      const executed = await createOrder(2);
+      const cancelled = await createOrder(3);
+      expect(await shariaDCA.getTotalEscrowed(usdcAddress)).to.equal(AMOUNT_PER_INTERVAL * 5n);
+
+      await executeWhenReady(executed);
+      await shariaDCA.connect(user).cancelDCAOrder(cancelled);
+
+      expect(await shariaDCA.getTotalEscrowed(usdcAddress)).to.equal(AMOUNT_PER_INTERVAL);
+      expect(await usdc.balanceOf(dcaAddress)).to.equal(await shariaDCA.getTotalEscrowed(usdcAddress));
+    });
+  });
+
+  describe("Auto Pause", function () {
+    let failingOrderId: bigint;
+
+    beforeEach(async function () {
+      // ETH has no pool, so swaps into it always fail
+      const MockERC20 = await ethers.getContractFactory("MockERC20");
+      const eth = await MockERC20.deploy("Ethereum", "ETH", 18);
+      await shariaCompliance.registerShariaCoin("ETH", "Ethereum", "ETH", await eth.getAddress(), "Test", 0);
+
+      await shariaDCA
+        .connect(user)
+        .createDCAOrderWithToken(await usdc.getAddress(), await eth.getAddress(), AMOUNT_PER_INTERVAL, HOUR, 2, "");
+      failingOrderId = (await shariaDCA.nextOrderId()) - 1n;
+
+      await shariaDCA.setMaxConsecutiveFailures(2);
+      await time.increaseTo((await shariaDCA.getDCAOrder(failingOrderId)).nextExecutionTime);
+    });
+
+    function performUpkeep(orderId: bigint) {
+      const performData = ethers.AbiCoder.defaultAbiCoder().encode(["uint256[]"], [[orderId]]);
+      return shariaDCA.performUpkeep(performData);
+    }
+
+    it("Should auto-pause an order after repeated failures", async function () {
+      await performUpkeep(failingOrderId);
+      expect((await shariaDCA.getDCAOrder(failingOrderId)).isActive).to.be.true;
+
+      await time.increase(HOUR);
+      await expect(performUpkeep(failingOrderId))
+        .to.emit(shariaDCA, "DCAOrderAutoPaused")
+        .withArgs(failingOrderId, user.address, 2);
+
+      expect((await shariaDCA.getDCAOrder(failingOrderId)).isActive).to.be.false;
+      expect(await shariaDCA.autoPaused(failingOrderId)).to.be.true;
+      expect(await shariaDCA.getActiveOrderCount()).to.equal(0);
+    });
+
+    it("Should count at most one failure per due window", async function () {
+      await shariaDCA.executeDCAOrdersBatch([failingOrderId, failingOrderId, failingOrderId]);
+      expect(await shariaDCA.consecutiveFailures(failingOrderId)).to.equal(1);
+
+      await performUpkeep(failingOrderId);
+      expect(await shariaDCA.consecutiveFailures(failingOrderId)).to.equal(1);
+      expect((await shariaDCA.getDCAOrder(failingOrderId)).isActive).to.be.true;
+    });
+
+    it("Should let the owner cancel or resume an auto-paused order", async function () {
+      await performUpkeep(failingOrderId);
+      await time.increase(HOUR);
+      await performUpkeep(failingOrderId);
+
+      await expect(shariaDCA.connect(user).resumeDCAOrder(failingOrderId))
+        .to.emit(shariaDCA, "DCAOrderResumed")
+        .withArgs(failingOrderId);
+      expect(await shariaDCA.consecutiveFailures(failingOrderId)).to.equal(0);
+      expect(await shariaDCA.getActiveOrderCount()).to.equal(1);
+
+      await performUpkeep(failingOrderId);
+      await time.increase(HOUR);
+      await performUpkeep(failingOrderId);
+      const balanceBefore = await usdc.balanceOf(user.address);
+
+      await shariaDCA.connect(user).cancelDCAOrder(failingOrderId);
+
+      expect(await usdc.balanceOf(user.address)).to.equal(balanceBefore + AMOUNT_PER_INTERVAL * 2n);
+      expect(await shariaDCA.getActiveOrderCount()).to.equal(0);
+    });
+  });
+});
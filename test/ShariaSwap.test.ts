@@ -0,0 +1,111 @@
+import { expect } from "chai";
+import { ethers } from "hardhat";
+import { time } from "@nomicfoundation/hardhat-network-helpers";
+import { ShariaCompliance, ShariaSwap, MockERC20 } from "../typechain-types";
+import { SignerWithAddress } from "@nomicfoundation/hardhat-ethers/signers";
+
+describe("ShariaSwap", function () {
+  let shariaCompliance: ShariaCompliance;
+  let shariaSwap: ShariaSwap;
+  let usdc: MockERC20;
+  let btc: MockERC20;
+  let owner: SignerWithAddress;
+  let user: SignerWithAddress;
+
+  const AMOUNT_IN = ethers.parseEther("10");
+  const HOUR = 3600;
+
+  beforeEach(async function () {
+    [owner, user] = await ethers.getSigners();
+
+    const MockERC20 = await ethers.getContractFactory("MockERC20");
+    usdc = await MockERC20.deploy("USD Coin", "USDC", 18);
+    btc = await MockERC20.deploy("Bitcoin", "BTC", 18);
+    const weth = await MockERC20.deploy("Wrapped DEV", "WDEV", 18);
+
+    const ShariaCompliance = await ethers.getContractFactory("ShariaCompliance");
+    shariaCompliance = await ShariaCompliance.deploy();
+    await shariaCompliance.registerShariaCoin("USDC", "USD Coin", "USDC", await usdc.getAddress(), "Test", 0);
+    await shariaCompliance.registerShariaCoin("BTC", "Bitcoin", "BTC", await btc.getAddress(), "Test", 0);
+
+    // AMM with a USDC/BTC pool so swaps can execute
+    const SimpleFactory = await ethers.getContractFactory("SimpleFactory");
+    const factory = await SimpleFactory.deploy();
+    const SimpleRouter = await ethers.getContractFactory("SimpleRouter");
+    const router = await SimpleRouter.deploy(await factory.getAddress(), await weth.getAddress());
+
+    await factory.createPair(await usdc.getAddress(), await btc.getAddress());
+    const liquidity = ethers.parseEther("1000000");
+    await usdc.mint(owner.address, liquidity);
+    await btc.mint(owner.address, liquidity);
+    await usdc.approve(await router.getAddress(), liquidity);
+    await btc.approve(await router.getAddress(), liquidity);
+    await router.addLiquidity(
+      await usdc.getAddress(),
+      await btc.getAddress(),
+      liquidity,
+      liquidity,
+      0,
+      0,
+      owner.address,
+      (await time.latest()) + HOUR
+    );
+
+    const ShariaSwap = await ethers.getContractFactory("ShariaSwap");
+    shariaSwap = await ShariaSwap.deploy(
+      await shariaCompliance.getAddress(),
+      await router.getAddress(),
+      await weth.getAddress(),
+      await factory.getAddress()
+    );
+
+    await usdc.mint(user.address, ethers.parseEther("1000"));
+    await usdc.connect(user).approve(await shariaSwap.getAddress(), ethers.MaxUint256);
+  });
+
+  async function swapUsdcForBtc() {
+    return shariaSwap
+      .connect(user)
+      .swapShariaCompliant(
+        await usdc.getAddress(),
+        await btc.getAddress(),
+        AMOUNT_IN,
+        0,
+        (await time.latest()) + HOUR
+      );
+  }
+
+  describe("Paused Target", function () {
+    it("Should reject token and DEV swaps into a paused coin", async function () {
+      await shariaCompliance.setCoinPaused("BTC", true, "Under re-review");
+
+      await expect(swapUsdcForBtc()).to.be.revertedWithCustomError(shariaCompliance, "CoinPaused");
+      await expect(
+        shariaSwap
+          .connect(user)
+          .swapGLMRForToken(await btc.getAddress(), 0, (await time.latest()) + HOUR, { value: AMOUNT_IN })
+      ).to.be.revertedWithCustomError(shariaCompliance, "CoinPaused");
+    });
+
+    it("Should allow swaps again once the coin is resumed", async function () {
+      await shariaCompliance.setCoinPaused("BTC", true, "Under re-review");
+      await shariaCompliance.setCoinPaused("BTC", false, "");
+
+      await expect(swapUsdcForBtc()).to.emit(shariaSwap, "SwapExecuted");
+    });
+  });
+
+  describe("Cost Basis", function () {
+    it("Should accumulate the amount spent across buys", async function () {
+      const usdcAddress = await usdc.getAddress();
+      const btcAddress = await btc.getAddress();
+
+      await swapUsdcForBtc();
+      await swapUsdcForBtc();
+
+      expect(await shariaSwap.getUserCostBasis(user.address, btcAddress, usdcAddress)).to.equal(AMOUNT_IN * 2n);
+      expect(await shariaSwap.getUserCostBasis(user.address, usdcAddress, btcAddress)).to.equal(0);
+      expect(await shariaSwap.getUserCostBasis(owner.address, btcAddress, usdcAddress)).to.equal(0);
+    });
+  });
+});